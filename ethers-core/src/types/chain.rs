@@ -1,11 +1,19 @@
-use super::{U128, U256, U512, U64};
-use serde::{Deserialize, Serialize, Serializer};
+use super::{Address, BlockNumber, H160, H256, U128, U256, U512, U64};
+use crate::utils::get_create2_address_from_hash;
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use std::{
+    cmp,
     convert::{TryFrom, TryInto},
     fmt,
     time::Duration,
 };
-use strum::{AsRefStr, EnumCount, EnumIter, EnumString, EnumVariantNames};
+use strum::{
+    AsRefStr, EnumCount, EnumIter, EnumMessage, EnumString, EnumVariantNames, VariantNames,
+};
+use url::Url;
 
 // compatibility re-export
 #[doc(hidden)]
@@ -33,12 +41,11 @@ pub type ParseChainError = TryFromPrimitiveError<Chain>;
     AsRefStr,         // also for fmt::Display and serde::Serialize
     EnumVariantNames, // Self::VARIANTS
     EnumString,       // FromStr, TryFrom<&str>
+    EnumMessage,      // get_serializations, for all names and aliases
+    TryFromPrimitive, // TryFrom<u64>
     EnumIter,
     EnumCount,
-    TryFromPrimitive, // TryFrom<u64>
-    Deserialize,
 )]
-#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "kebab-case")]
 #[repr(u64)]
 pub enum Chain {
@@ -137,7 +144,7 @@ macro_rules! impl_into_numeric {
 }
 
 macro_rules! impl_try_from_numeric {
-    ($($native:ty)+ ; $($primitive:ty)*) => {
+    ($($native:ty)+ ; $($signed:ty)+ ; $($primitive:ty)*) => {
         $(
             impl TryFrom<$native> for Chain {
                 type Error = ParseChainError;
@@ -148,6 +155,20 @@ macro_rules! impl_try_from_numeric {
             }
         )+
 
+        $(
+            impl TryFrom<$signed> for Chain {
+                type Error = ParseChainError;
+
+                fn try_from(value: $signed) -> Result<Self, Self::Error> {
+                    if value < 0 {
+                        // `number` is a `u64`, so negative values are reported as 0
+                        return Err(ParseChainError { number: 0 })
+                    }
+                    (value as u64).try_into()
+                }
+            }
+        )+
+
         $(
             impl TryFrom<$primitive> for Chain {
                 type Error = ParseChainError;
@@ -181,7 +202,7 @@ impl TryFrom<U64> for Chain {
     }
 }
 
-impl_try_from_numeric!(u8 u16 u32 usize; U128 U256 U512);
+impl_try_from_numeric!(u8 u16 u32 usize; i8 i16 i32 i64 isize; U128 U256 U512);
 
 impl fmt::Display for Chain {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -198,6 +219,222 @@ impl Serialize for Chain {
     }
 }
 
+// Accepts everything `FromStr` does, case-insensitively, as well as the `snake_case` names that
+// were previously used for deserialization and the chain id, as a number or a decimal or hex string
+impl<'de> Deserialize<'de> for Chain {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ChainVisitor;
+
+        impl<'de> Visitor<'de> for ChainVisitor {
+            type Value = Chain;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a chain name or id")
+            }
+
+            fn visit_u64<E: de::Error>(self, id: u64) -> Result<Self::Value, E> {
+                Chain::try_from(id)
+                    .map_err(|_| de::Error::custom(format!("unknown chain id: {id}")))
+            }
+
+            // some formats, like TOML, only have signed integers
+            fn visit_i64<E: de::Error>(self, id: i64) -> Result<Self::Value, E> {
+                let id = u64::try_from(id)
+                    .map_err(|_| de::Error::custom(format!("invalid chain id: {id}")))?;
+                self.visit_u64(id)
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                match parse_chain_id_or_name(s)? {
+                    ChainId::Named(chain) => Ok(chain),
+                    ChainId::Id(id) => self.visit_u64(id),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(ChainVisitor)
+    }
+}
+
+#[cfg(feature = "clap")]
+impl clap::ValueEnum for Chain {
+    fn value_variants<'a>() -> &'a [Self] {
+        use strum::IntoEnumIterator;
+
+        static VARIANTS: once_cell::sync::Lazy<Vec<Chain>> =
+            once_cell::sync::Lazy::new(|| Chain::iter().collect());
+        &VARIANTS
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        // `as_ref` borrows from `self`, but clap needs `'static` names.
+        let (name, aliases): (Vec<&'static str>, Vec<&'static str>) =
+            self.get_serializations().iter().partition(|name| **name == self.as_ref());
+        Some(clap::builder::PossibleValue::new(*name.first()?).aliases(aliases))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Chain {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use strum::IntoEnumIterator;
+
+        let index = u.choose_index(Chain::COUNT)?;
+        Ok(Chain::iter().nth(index).expect("index is less than Chain::COUNT"))
+    }
+}
+
+/// Returns a [`proptest`] strategy that yields every [`Chain`] variant with equal probability.
+#[cfg(feature = "proptest")]
+pub fn chain_strategy() -> impl proptest::strategy::Strategy<Value = Chain> {
+    use strum::IntoEnumIterator;
+
+    proptest::sample::select(Chain::iter().collect::<Vec<_>>())
+}
+
+/// (De)serializes a [`Chain`] as its numeric chain id rather than its name.
+///
+/// # Examples
+///
+/// ```
+/// use ethers_core::types::Chain;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "ethers_core::types::chain::as_num")]
+///     chain: Chain,
+/// }
+///
+/// let config = Config { chain: Chain::Polygon };
+/// assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"chain":137}"#);
+/// ```
+pub mod as_num {
+    use super::Chain;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::convert::TryFrom;
+
+    /// Serializes the chain as its `u64` chain id.
+    pub fn serialize<S>(chain: &Chain, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_u64(*chain as u64)
+    }
+
+    /// Deserializes a chain from its `u64` chain id.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Chain, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let id = u64::deserialize(deserializer)?;
+        Chain::try_from(id).map_err(|_| de::Error::custom(format!("unknown chain id: {id}")))
+    }
+}
+
+/// The [EIP-4788](https://eips.ethereum.org/EIPS/eip-4788) beacon roots contract address:
+/// `0x000F3df6D732807Ef1319fB7B8bB8522d0Beac02`
+const BEACON_ROOTS_ADDRESS: Address = H160([
+    0x00, 0x0f, 0x3d, 0xf6, 0xd7, 0x32, 0x80, 0x7e, 0xf1, 0x31, 0x9f, 0xb7, 0xb8, 0xbb, 0x85, 0x22,
+    0xd0, 0xbe, 0xac, 0x02,
+]);
+
+/// The OP-stack `GasPriceOracle` predeploy address:
+/// `0x420000000000000000000000000000000000000F`
+const OP_GAS_PRICE_ORACLE_ADDRESS: Address = H160([
+    0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x0f,
+]);
+
+/// The OP-stack `L2CrossDomainMessenger` predeploy address:
+/// `0x4200000000000000000000000000000000000007`
+const OP_L2_CROSS_DOMAIN_MESSENGER_ADDRESS: Address = H160([
+    0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x07,
+]);
+
+/// The Arbitrum `ArbSys` precompile address: `0x0000000000000000000000000000000000000064`
+const ARB_SYS_ADDRESS: Address = H160([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x64,
+]);
+
+/// The canonical Multicall3 deployment address: `0xcA11bde05977b3631167028862bE2a173976CA11`
+const MULTICALL3_ADDRESS: Address = H160([
+    0xca, 0x11, 0xbd, 0xe0, 0x59, 0x77, 0xb3, 0x63, 0x11, 0x67, 0x02, 0x88, 0x62, 0xbe, 0x2a, 0x17,
+    0x39, 0x76, 0xca, 0x11,
+]);
+
+/// The ENS registry address: `0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e`
+const ENS_REGISTRY_ADDRESS: Address = H160([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x2e, 0x07, 0x4e, 0xc6, 0x9a, 0x0d, 0xfb, 0x29, 0x97, 0xba,
+    0x6c, 0x7d, 0x2e, 0x1e,
+]);
+
+/// The SPDX license identifiers accepted by Etherscan's contract verification API, in the order of
+/// their numeric `licenseType` codes (starting at 1).
+const ETHERSCAN_VERIFICATION_LICENSES: &[&str] = &[
+    "UNLICENSED",
+    "Unlicense",
+    "MIT",
+    "GPL-2.0",
+    "GPL-3.0",
+    "LGPL-2.1",
+    "LGPL-3.0",
+    "BSD-2-Clause",
+    "BSD-3-Clause",
+    "MPL-2.0",
+    "OSL-3.0",
+    "Apache-2.0",
+    "AGPL-3.0",
+    "BUSL-1.1",
+];
+
+/// The Mainnet genesis block hash:
+/// `0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3`
+const MAINNET_GENESIS_HASH: H256 = H256([
+    0xd4, 0xe5, 0x67, 0x40, 0xf8, 0x76, 0xae, 0xf8, 0xc0, 0x10, 0xb8, 0x6a, 0x40, 0xd5, 0xf5, 0x67,
+    0x45, 0xa1, 0x18, 0xd0, 0x90, 0x6a, 0x34, 0xe6, 0x9a, 0xec, 0x8c, 0x0d, 0xb1, 0xcb, 0x8f, 0xa3,
+]);
+
+/// The Ropsten genesis block hash:
+/// `0x41941023680923e0fe4d74a34bdac8141f2540e3ae90623718e47d66d1ca4a2d`
+const ROPSTEN_GENESIS_HASH: H256 = H256([
+    0x41, 0x94, 0x10, 0x23, 0x68, 0x09, 0x23, 0xe0, 0xfe, 0x4d, 0x74, 0xa3, 0x4b, 0xda, 0xc8, 0x14,
+    0x1f, 0x25, 0x40, 0xe3, 0xae, 0x90, 0x62, 0x37, 0x18, 0xe4, 0x7d, 0x66, 0xd1, 0xca, 0x4a, 0x2d,
+]);
+
+/// The Rinkeby genesis block hash:
+/// `0x6341fd3daf94b748c72ced5a5b26028f2474f5f00d824504e4fa37a75767e177`
+const RINKEBY_GENESIS_HASH: H256 = H256([
+    0x63, 0x41, 0xfd, 0x3d, 0xaf, 0x94, 0xb7, 0x48, 0xc7, 0x2c, 0xed, 0x5a, 0x5b, 0x26, 0x02, 0x8f,
+    0x24, 0x74, 0xf5, 0xf0, 0x0d, 0x82, 0x45, 0x04, 0xe4, 0xfa, 0x37, 0xa7, 0x57, 0x67, 0xe1, 0x77,
+]);
+
+/// The Goerli genesis block hash:
+/// `0xbf7e331f7f7c1dd2e05159666b3bf8bc7a8a3a9eb1d518969eab529dd9b88c1a`
+const GOERLI_GENESIS_HASH: H256 = H256([
+    0xbf, 0x7e, 0x33, 0x1f, 0x7f, 0x7c, 0x1d, 0xd2, 0xe0, 0x51, 0x59, 0x66, 0x6b, 0x3b, 0xf8, 0xbc,
+    0x7a, 0x8a, 0x3a, 0x9e, 0xb1, 0xd5, 0x18, 0x96, 0x9e, 0xab, 0x52, 0x9d, 0xd9, 0xb8, 0x8c, 0x1a,
+]);
+
+/// The Kovan genesis block hash:
+/// `0xa3c565fc15c7478862d50ccd6561e3c06b24cc509bf388941c25ea985ce32cb9`
+const KOVAN_GENESIS_HASH: H256 = H256([
+    0xa3, 0xc5, 0x65, 0xfc, 0x15, 0xc7, 0x47, 0x88, 0x62, 0xd5, 0x0c, 0xcd, 0x65, 0x61, 0xe3, 0xc0,
+    0x6b, 0x24, 0xcc, 0x50, 0x9b, 0xf3, 0x88, 0x94, 0x1c, 0x25, 0xea, 0x98, 0x5c, 0xe3, 0x2c, 0xb9,
+]);
+
+/// The Sepolia genesis block hash:
+/// `0x25a5cc106eea7138acab33231d7160d69cb777ee0c2c553fcddf5138993e6dd9`
+const SEPOLIA_GENESIS_HASH: H256 = H256([
+    0x25, 0xa5, 0xcc, 0x10, 0x6e, 0xea, 0x71, 0x38, 0xac, 0xab, 0x33, 0x23, 0x1d, 0x71, 0x60, 0xd6,
+    0x9c, 0xb7, 0x77, 0xee, 0x0c, 0x2c, 0x55, 0x3f, 0xcd, 0xdf, 0x51, 0x38, 0x99, 0x3e, 0x6d, 0xd9,
+]);
+
 // NB: all utility functions *should* be explicitly exhaustive (not use `_` matcher) so we don't
 //     forget to update them when adding a new `Chain` variant.
 impl Chain {
@@ -241,7 +478,26 @@ impl Chain {
     /// Returns the chain's blockchain explorer and its API (Etherscan and Etherscan-like) URLs.
     ///
     /// Returns `(API URL, BASE_URL)`, like `("https://api(-chain).etherscan.io/api", "https://etherscan.io")`
+    #[deprecated = "Use `etherscan_api_url` and `etherscan_base_url` instead"]
     pub const fn etherscan_urls(&self) -> Option<(&'static str, &'static str)> {
+        self.explorer_urls()
+    }
+
+    /// Returns the API URL of the chain's Etherscan-like explorer, like
+    /// `https://api.etherscan.io/api`.
+    pub fn etherscan_api_url(&self) -> Option<Url> {
+        let (api, _) = self.explorer_urls()?;
+        Some(Url::parse(api).expect("valid explorer URL"))
+    }
+
+    /// Returns the base URL of the chain's Etherscan-like explorer, like `https://etherscan.io`.
+    pub fn etherscan_base_url(&self) -> Option<Url> {
+        let (_, base) = self.explorer_urls()?;
+        Some(Url::parse(base).expect("valid explorer URL"))
+    }
+
+    /// Returns `(API URL, BASE_URL)` of the chain's Etherscan-like explorer.
+    const fn explorer_urls(&self) -> Option<(&'static str, &'static str)> {
         use Chain::*;
 
         let urls = match self {
@@ -316,12 +572,12 @@ impl Chain {
             }
             Evmos => ("https://evm.evmos.org/api", "https://evm.evmos.org/"),
             EvmosTestnet => ("https://evm.evmos.dev/api", "https://evm.evmos.dev/"),
-            Celo => ("https://explorer.celo.org/mainnet", "https://explorer.celo.org/mainnet/api"),
+            Celo => ("https://explorer.celo.org/mainnet/api", "https://explorer.celo.org/mainnet"),
             CeloAlfajores => {
-                ("https://explorer.celo.org/alfajores", "https://explorer.celo.org/alfajores/api")
+                ("https://explorer.celo.org/alfajores/api", "https://explorer.celo.org/alfajores")
             }
             CeloBaklava => {
-                ("https://explorer.celo.org/baklava", "https://explorer.celo.org/baklava/api")
+                ("https://explorer.celo.org/baklava/api", "https://explorer.celo.org/baklava")
             }
             AnvilHardhat | Dev | Morden | MoonbeamDev => {
                 // this is explicitly exhaustive so we don't forget to add new urls when adding a
@@ -358,31 +614,5089 @@ impl Chain {
             CeloAlfajores |
             CeloBaklava => true,
 
+            // Shut down long before EIP-1559 and typed transactions
+            Morden => true,
+
             // Known EIP-1559 chains
             Mainnet | Goerli | Sepolia | Polygon | PolygonMumbai | Avalanche | AvalancheFuji => {
                 false
             }
 
+            // Gnosis activated London, including EIP-1559, at block 19,040,000 (December 2021);
+            // Chiado has run London since genesis
+            XDai | Chiado => false,
+
+            // EIP-1559 transactions are supported, see
+            // <https://docs.moonbeam.network/learn/features/eth-compatibility/>
+            Moonbeam | MoonbeamDev | Moonriver | Moonbase => false,
+
+            // Cronos supports EIP-1559, see <https://github.com/gakonst/ethers-rs/pull/1246>
+            Cronos | CronosTestnet => false,
+
             // Unknown / not applicable, default to false for backwards compatibility
-            Dev | AnvilHardhat | Morden | Ropsten | Rinkeby | Cronos | CronosTestnet | Kovan |
-            Sokol | Poa | XDai | Moonbeam | MoonbeamDev | Moonriver | Moonbase | Evmos |
-            EvmosTestnet | Chiado | Aurora | AuroraTestnet => false,
+            Dev | AnvilHardhat | Ropsten | Rinkeby | Kovan | Sokol | Poa | Evmos |
+            EvmosTestnet | Aurora | AuroraTestnet => false,
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use strum::IntoEnumIterator;
+    /// Returns the number of confirmations after which a transaction can be considered safe from
+    /// reorgs for everyday use.
+    ///
+    /// **Note:** this is a sensible default derived from each network's consensus, not a
+    /// guarantee of finality.
+    pub const fn safe_confirmation_blocks(&self) -> u64 {
+        use Chain::*;
 
-    #[test]
-    fn test_default_chain() {
-        assert_eq!(serde_json::to_string(&Chain::default()).unwrap(), "\"mainnet\"");
+        match self {
+            Mainnet | Morden | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Rsk | Poa |
+            Sokol | XDai | Chiado => 12,
+
+            // rollups inherit their ordering from the sequencer
+            Optimism | OptimismKovan | OptimismGoerli | Arbitrum | ArbitrumTestnet |
+            ArbitrumGoerli | ArbitrumNova => 1,
+
+            // deep reorgs are known to have happened on Polygon PoS
+            Polygon | PolygonMumbai => 128,
+
+            // 2/3 of the 21 validators + 1
+            BinanceSmartChain | BinanceSmartChainTestnet => 15,
+
+            Moonbeam | MoonbeamDev | Moonriver | Moonbase | Aurora | AuroraTestnet => 2,
+
+            // BFT consensus with single-block finality
+            Avalanche | AvalancheFuji | Fantom | FantomTestnet | Cronos | CronosTestnet |
+            Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Celo | CeloAlfajores |
+            CeloBaklava => 1,
+
+            Dev | AnvilHardhat => 1,
+        }
     }
 
-    #[test]
-    fn test_enum_iter() {
-        assert_eq!(Chain::COUNT, Chain::iter().size_hint().0);
+    /// Returns a `"{current}/{target} confirmations"` label, using
+    /// [`safe_confirmation_blocks`](Self::safe_confirmation_blocks) as the target.
+    pub fn confirmations_label(&self, current: u64) -> String {
+        format!("{current}/{} confirmations", self.safe_confirmation_blocks())
+    }
+
+    /// Same as [`confirmations_label`](Self::confirmations_label), but also appends the estimated
+    /// time until the target is reached, like `"3/12 confirmations (~1m 57s remaining)"`.
+    ///
+    /// The estimate is omitted if the target has been reached or if the chain has no
+    /// [`average_blocktime_hint`](Self::average_blocktime_hint).
+    pub fn confirmations_label_with_eta(&self, current: u64) -> String {
+        let label = self.confirmations_label(current);
+        let remaining = self.safe_confirmation_blocks().saturating_sub(current);
+        match self.average_blocktime_hint() {
+            Some(blocktime) if remaining > 0 => {
+                let secs = (blocktime * remaining as u32).as_secs();
+                if secs < 60 {
+                    format!("{label} (~{secs}s remaining)")
+                } else {
+                    format!("{label} (~{}m {}s remaining)", secs / 60, secs % 60)
+                }
+            }
+            _ => label,
+        }
+    }
+
+    /// Returns whether the chain's dominant client implements the `eth_getBlockReceipts` RPC
+    /// method, which fetches all of a block's receipts in a single call.
+    ///
+    /// **Note:** this is a best-effort hint, individual RPC providers may still not expose the
+    /// method.
+    pub const fn supports_get_block_receipts(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // geth >= 1.13 and its forks, erigon, nethermind, reth, anvil
+            Mainnet |
+            Sepolia |
+            Optimism |
+            Arbitrum |
+            ArbitrumNova |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Polygon |
+            XDai |
+            Chiado |
+            Dev |
+            AnvilHardhat => true,
+
+            // Shut down, or running clients that predate the method
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | OptimismKovan | OptimismGoerli |
+            ArbitrumTestnet | ArbitrumGoerli | PolygonMumbai | Poa | Sokol | Rsk => false,
+
+            // Unknown
+            Cronos | CronosTestnet | Fantom | FantomTestnet | Moonbeam | MoonbeamDev |
+            Moonriver | Moonbase | Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet |
+            Avalanche | AvalancheFuji | Celo | CeloAlfajores | CeloBaklava | Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain exposes the parent beacon block root in the EVM, as specified in
+    /// [EIP-4788](https://eips.ethereum.org/EIPS/eip-4788) (Cancun).
+    pub const fn supports_beacon_block_root(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // Cancun / Dencun
+            Mainnet | Goerli | Sepolia | XDai | Chiado | Dev | AnvilHardhat => true,
+            // Ecotone
+            Optimism => true,
+
+            // Not activated, or no beacon chain to expose roots from
+            Morden | Ropsten | Rinkeby | Kovan | OptimismKovan | OptimismGoerli | Arbitrum |
+            ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova | Polygon | PolygonMumbai => false,
+
+            // Unknown
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Evmos |
+            EvmosTestnet |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns the address of the [EIP-4788](https://eips.ethereum.org/EIPS/eip-4788) beacon
+    /// roots contract, if the chain [supports it](Self::supports_beacon_block_root).
+    pub const fn beacon_roots_address(&self) -> Option<Address> {
+        if self.supports_beacon_block_root() {
+            Some(BEACON_ROOTS_ADDRESS)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether querying the `"pending"` block tag returns a meaningful pending block, as
+    /// opposed to the latest block or an error.
+    ///
+    /// **Note:** this is a best-effort hint based on the chain's dominant client.
+    pub const fn supports_pending_block(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Sepolia |
+            Polygon |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Dev |
+            AnvilHardhat => true,
+
+            // Rollups don't have a public mempool, so the pending block is the latest block
+            Optimism | OptimismKovan | OptimismGoerli | Arbitrum | ArbitrumTestnet |
+            ArbitrumGoerli | ArbitrumNova => false,
+
+            // Deprecated
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | PolygonMumbai | Poa | Sokol => false,
+
+            // Unknown
+            Cronos | CronosTestnet | Rsk | XDai | Chiado | Fantom | FantomTestnet | Moonbeam |
+            MoonbeamDev | Moonriver | Moonbase | Evmos | EvmosTestnet | Oasis | Emerald |
+            EmeraldTestnet | Avalanche | AvalancheFuji | Celo | CeloAlfajores | CeloBaklava |
+            Aurora | AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the network is permissioned, i.e. blocks are produced by a fixed set of
+    /// authorities rather than through open consensus.
+    ///
+    /// Permissioned networks may additionally restrict which accounts can transact or deploy
+    /// contracts, so tooling may want to warn users that they need to be allowlisted.
+    pub const fn is_permissioned(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // Proof of Authority (Aura, Clique)
+            Poa | Sokol | Kovan | Rinkeby => true,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Goerli |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns a conservative number of confirmations after which a transaction can be
+    /// considered final for custody or treasury purposes.
+    ///
+    /// These are deliberately far above
+    /// [`safe_confirmation_blocks`](Self::safe_confirmation_blocks): they target the point at
+    /// which a reorg is practically impossible (e.g. a finalized checkpoint on proof-of-stake
+    /// Ethereum), trading latency for safety.
+    pub const fn exchange_grade_confirmations(&self) -> u64 {
+        use Chain::*;
+
+        match self {
+            // two epochs, i.e. a finalized checkpoint
+            Mainnet | Morden | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Poa | Sokol |
+            XDai | Chiado => 64,
+
+            // ~20 minutes, until the batch containing the block is finalized on L1
+            Optimism | OptimismKovan | OptimismGoerli => 600,
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => 4_800,
+
+            Polygon | PolygonMumbai => 256,
+
+            BinanceSmartChain | BinanceSmartChainTestnet => 50,
+
+            Rsk => 100,
+
+            // BFT consensus with single-block finality, the margin only guards against lagging
+            // nodes
+            Avalanche | AvalancheFuji | Fantom | FantomTestnet | Cronos | CronosTestnet |
+            Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Celo | CeloAlfajores |
+            CeloBaklava | Moonbeam | MoonbeamDev | Moonriver | Moonbase | Aurora |
+            AuroraTestnet => 10,
+
+            Dev | AnvilHardhat => 1,
+        }
+    }
+
+    /// Returns the maximum number of requests a JSON-RPC batch should contain on this chain, or
+    /// `None` if unknown or unlimited.
+    ///
+    /// **Note:** this is advisory only and based on the default limits of the chain's common
+    /// clients, individual RPC providers may enforce lower limits.
+    pub const fn rpc_max_batch_size(&self) -> Option<usize> {
+        use Chain::*;
+
+        let size = match self {
+            // erigon's default of 100 is the lowest among the common execution clients
+            Mainnet | Sepolia | XDai | Chiado | Polygon => 100,
+
+            // geth forks, which default to 1000
+            Optimism | Arbitrum | ArbitrumNova | BinanceSmartChain | BinanceSmartChainTestnet => {
+                1_000
+            }
+
+            // Unknown, unlimited (local nodes), or shut down
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | OptimismKovan | OptimismGoerli |
+            ArbitrumTestnet | ArbitrumGoerli | Cronos | CronosTestnet | Rsk | Poa | Sokol |
+            PolygonMumbai | Fantom | FantomTestnet | Moonbeam | MoonbeamDev | Moonriver |
+            Moonbase | Dev | AnvilHardhat | Evmos | EvmosTestnet | Oasis | Emerald |
+            EmeraldTestnet | Avalanche | AvalancheFuji | Celo | CeloAlfajores | CeloBaklava |
+            Aurora | AuroraTestnet => return None,
+        };
+
+        Some(size)
+    }
+
+    /// Returns whether the chain's common RPC endpoints are known to accept JSON-RPC batch
+    /// requests, see [`rpc_max_batch_size`](Self::rpc_max_batch_size).
+    ///
+    /// Chains for which support is unknown default to `false`.
+    pub const fn rpc_batch_supported(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Sepolia |
+            XDai |
+            Chiado |
+            Polygon |
+            Optimism |
+            Arbitrum |
+            ArbitrumNova |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet => true,
+
+            // local nodes
+            Dev | AnvilHardhat => true,
+
+            // Unknown, or shut down
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | OptimismKovan | OptimismGoerli |
+            ArbitrumTestnet | ArbitrumGoerli | Cronos | CronosTestnet | Rsk | Poa | Sokol |
+            PolygonMumbai | Fantom | FantomTestnet | Moonbeam | MoonbeamDev | Moonriver |
+            Moonbase | Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Avalanche |
+            AvalancheFuji | Celo | CeloAlfajores | CeloBaklava | Aurora | AuroraTestnet => false,
+        }
+    }
+
+    /// Returns the most efficient way to bundle multiple read calls on this chain: a JSON-RPC
+    /// batch where [supported](Self::rpc_batch_supported), else an aggregated call to the chain's
+    /// [Multicall3](Self::multicall3_address) deployment, else sequential requests.
+    pub const fn read_batching_strategy(&self) -> ReadBatching {
+        if self.rpc_batch_supported() {
+            return ReadBatching::JsonRpcBatch
+        }
+        match self.multicall3_address() {
+            Some(address) => ReadBatching::Multicall3(address),
+            None => ReadBatching::Sequential,
+        }
+    }
+
+    /// Returns the minimum base fee per blob gas of [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844)
+    /// blob transactions, if the chain accepts them.
+    ///
+    /// This is the floor for a blob transaction's `max_fee_per_blob_gas`.
+    pub const fn min_blob_base_fee(&self) -> Option<U256> {
+        use Chain::*;
+
+        let wei = match self {
+            Mainnet |
+            Goerli |
+            Sepolia |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Dev |
+            AnvilHardhat => 1,
+            // Gnosis raised `MIN_BASE_FEE_PER_BLOB_GAS` to 1 gwei
+            XDai | Chiado => 1_000_000_000,
+
+            // No blob transactions
+            Morden | Ropsten | Rinkeby | Kovan | Optimism | OptimismKovan | OptimismGoerli |
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova | Cronos |
+            CronosTestnet | Rsk | Poa | Sokol | Polygon | PolygonMumbai | Fantom |
+            FantomTestnet | Moonbeam | MoonbeamDev | Moonriver | Moonbase | Evmos |
+            EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Avalanche | AvalancheFuji |
+            Celo | CeloAlfajores | CeloBaklava | Aurora | AuroraTestnet => return None,
+        };
+
+        Some(U256([wei, 0, 0, 0]))
+    }
+
+    /// Returns whether the chain's dominant client implements the `eth_simulateV1` RPC method for
+    /// simulating a bundle of calls.
+    ///
+    /// When unsupported, callers should fall back to sequential `eth_call`s.
+    ///
+    /// **Note:** this is a best-effort hint, support depends on the client version run by the
+    /// RPC provider.
+    pub const fn supports_simulate_v1(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // geth >= 1.14.9, nethermind, reth, and their OP-stack forks
+            Mainnet | Sepolia | XDai | Chiado | Optimism => true,
+
+            // Shut down, or running clients that predate the method
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | OptimismKovan | OptimismGoerli |
+            ArbitrumTestnet | ArbitrumGoerli | PolygonMumbai | Poa | Sokol => false,
+
+            // Unknown
+            Arbitrum |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Polygon |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns all of the chain's metadata bundled in a single, serializable [`NetworkConfig`].
+    pub fn network_config(&self) -> NetworkConfig {
+        let (explorer_api_url, explorer_url) = match self.explorer_urls() {
+            Some((api, base)) => (Some(api), Some(base)),
+            None => (None, None),
+        };
+        NetworkConfig {
+            chain: *self,
+            chain_id: u64::from(*self),
+            explorer_api_url,
+            explorer_url,
+            average_blocktime_ms: self.average_blocktime_hint().map(|d| d.as_millis() as u64),
+            native_currency_symbol: self.native_currency_symbol(),
+            is_legacy: self.is_legacy(),
+            safe_confirmations: self.safe_confirmation_blocks(),
+            finalized_confirmations: self.finalized_confirmation_blocks(),
+        }
+    }
+
+    /// Returns whether the chain's dominant client exposes state diff tracing, either through
+    /// `trace_replayTransaction` with `stateDiff` or `debug_traceTransaction` with the
+    /// `prestateTracer` in diff mode.
+    ///
+    /// **Note:** this is a best-effort hint, RPC providers often disable the `debug` and `trace`
+    /// namespaces.
+    pub const fn supports_state_diff(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // geth >= 1.11 and its forks, erigon, nethermind, reth, anvil
+            Mainnet |
+            Sepolia |
+            XDai |
+            Chiado |
+            Optimism |
+            Arbitrum |
+            ArbitrumNova |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Polygon |
+            AnvilHardhat => true,
+
+            // Shut down, or running clients that predate diff mode
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | OptimismKovan | OptimismGoerli |
+            ArbitrumTestnet | ArbitrumGoerli | PolygonMumbai | Poa | Sokol => false,
+
+            // Unknown
+            Cronos | CronosTestnet | Rsk | Fantom | FantomTestnet | Moonbeam | MoonbeamDev |
+            Moonriver | Moonbase | Dev | Evmos | EvmosTestnet | Oasis | Emerald |
+            EmeraldTestnet | Avalanche | AvalancheFuji | Celo | CeloAlfajores | CeloBaklava |
+            Aurora | AuroraTestnet => false,
+        }
+    }
+
+    /// Returns the address of the OP-stack `GasPriceOracle` predeploy, which computes the L1 data
+    /// fee component of a transaction's fee.
+    ///
+    /// Returns `None` for chains which are not OP-stack chains.
+    pub const fn l1_gas_oracle_address(&self) -> Option<Address> {
+        use Chain::*;
+
+        match self {
+            Optimism | OptimismKovan | OptimismGoerli => Some(OP_GAS_PRICE_ORACLE_ADDRESS),
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => None,
+        }
+    }
+
+    /// Returns the chains whose name or aliases are closest to `input` by edit distance, closest
+    /// first.
+    ///
+    /// Useful for "did you mean ...?" suggestions when parsing a chain fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ethers_core::types::Chain;
+    ///
+    /// assert_eq!(Chain::suggest("optimsm").first(), Some(&Chain::Optimism));
+    /// ```
+    pub fn suggest(input: &str) -> Vec<Chain> {
+        use strum::IntoEnumIterator;
+
+        let input = input.to_lowercase();
+        // allow roughly one typo every three characters
+        let max_distance = cmp::max(1, input.len() / 3);
+
+        let mut suggestions = Chain::iter()
+            .filter_map(|chain| {
+                chain
+                    .get_serializations()
+                    .iter()
+                    .map(|name| edit_distance(&input, name))
+                    .min()
+                    .filter(|distance| *distance <= max_distance)
+                    .map(|distance| (distance, chain))
+            })
+            .collect::<Vec<_>>();
+        suggestions.sort_by_key(|(distance, _)| *distance);
+        suggestions.into_iter().map(|(_, chain)| chain).collect()
+    }
+
+    /// Returns whether the chain is EVM-equivalent, i.e. Ethereum bytecode and gas assumptions
+    /// transfer exactly, as opposed to merely EVM-compatible with quirks such as different gas
+    /// metering or missing opcodes.
+    ///
+    /// Gas should be re-estimated per chain when this returns `false`.
+    pub const fn is_evm_equivalent(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // geth and its forks
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Poa |
+            Sokol |
+            XDai |
+            Chiado |
+            Polygon |
+            PolygonMumbai |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Fantom |
+            FantomTestnet |
+            Dev |
+            AnvilHardhat => true,
+
+            // Bedrock
+            Optimism | OptimismGoerli => true,
+
+            // OVM
+            OptimismKovan => false,
+
+            // ArbOS gas accounting and `block.number` semantics
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => false,
+
+            // Non-standard execution environments or fee mechanics (Substrate, Cosmos SDK, NEAR,
+            // Celo fee currencies, ...)
+            Cronos | CronosTestnet | Rsk | Moonbeam | MoonbeamDev | Moonriver | Moonbase |
+            Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Celo | CeloAlfajores |
+            CeloBaklava | Aurora | AuroraTestnet => false,
+        }
+    }
+
+    /// Returns the reward percentiles to request from `eth_feeHistory` when estimating the
+    /// priority fee on this chain.
+    ///
+    /// Returns an empty slice for [legacy](Self::is_legacy) chains, which have no priority fee.
+    pub const fn default_reward_percentiles(&self) -> &'static [f64] {
+        use Chain::*;
+
+        match self {
+            Mainnet | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Cronos | CronosTestnet |
+            Poa | Sokol | XDai | Chiado | Moonbeam | MoonbeamDev | Moonriver | Moonbase |
+            Evmos | EvmosTestnet | Avalanche | AvalancheFuji | Aurora | AuroraTestnet | Dev |
+            AnvilHardhat => &[10.0, 50.0, 90.0],
+
+            // priority fees are very volatile, trim the tails
+            Polygon | PolygonMumbai => &[25.0, 50.0, 75.0],
+
+            // legacy
+            Morden |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Fantom |
+            FantomTestnet |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava => &[],
+        }
+    }
+
+    /// Returns whether the chain's [explorer](Self::etherscan_base_url) can return the source code
+    /// of verified contracts through `module=contract&action=getsourcecode`.
+    ///
+    /// **Note:** this is a best-effort hint per explorer.
+    pub const fn supports_get_source_code(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // Etherscan and its clones
+            Mainnet |
+            Goerli |
+            Sepolia |
+            Polygon |
+            PolygonMumbai |
+            Avalanche |
+            AvalancheFuji |
+            Optimism |
+            OptimismGoerli |
+            Fantom |
+            FantomTestnet |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Arbitrum |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Moonbeam |
+            Moonbase |
+            Moonriver |
+            Aurora |
+            AuroraTestnet => true,
+
+            // Blockscout
+            ArbitrumGoerli | XDai | Chiado | Sokol | Poa | Rsk | Emerald | EmeraldTestnet |
+            Evmos | EvmosTestnet | Celo | CeloAlfajores | CeloBaklava => true,
+
+            // Explorer has been shut down, or is not Etherscan-compatible
+            Ropsten | Kovan | Rinkeby | OptimismKovan | ArbitrumTestnet | Oasis => false,
+
+            // No explorer
+            AnvilHardhat | Dev | Morden | MoonbeamDev => false,
+        }
+    }
+
+    /// Computes the [EIP-1014](https://eips.ethereum.org/EIPS/eip-1014) `CREATE2` address of a
+    /// contract deployed by `deployer`.
+    ///
+    /// The address does not depend on the chain, which is what allows deploying a contract to the
+    /// same address on multiple chains, but is exposed here for convenience when writing
+    /// cross-chain deployment tooling.
+    ///
+    /// See also [`get_create2_address_from_hash`].
+    pub fn create2_address(&self, deployer: Address, salt: H256, init_code_hash: H256) -> Address {
+        get_create2_address_from_hash(deployer, salt, init_code_hash)
+    }
+
+    /// Returns whether the chain is a sidechain, i.e. it is secured by its own validators rather
+    /// than by Ethereum, unlike rollups which inherit the security of their L1.
+    ///
+    /// This is the case when its [`security_model`](Self::security_model) is
+    /// [`Sidechain`](SecurityModel::Sidechain).
+    pub const fn is_sidechain(&self) -> bool {
+        matches!(self.security_model(), SecurityModel::Sidechain)
+    }
+
+    /// Returns the chain's [`SecurityModel`], i.e. what secures the funds held on it.
+    pub const fn security_model(&self) -> SecurityModel {
+        use Chain::*;
+
+        match self {
+            Mainnet | Morden | Ropsten | Goerli | Sepolia => SecurityModel::EthereumL1,
+
+            Optimism | OptimismKovan | OptimismGoerli | Arbitrum | ArbitrumTestnet |
+            ArbitrumGoerli => SecurityModel::OptimisticRollup,
+            // AnyTrust, data availability is provided by a committee
+            ArbitrumNova => SecurityModel::OptimisticRollup,
+
+            Polygon |
+            PolygonMumbai |
+            XDai |
+            Chiado |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Rsk => SecurityModel::Sidechain,
+
+            // Standalone chains with their own validator sets
+            Cronos | CronosTestnet | Fantom | FantomTestnet | Moonbeam | Moonriver | Moonbase |
+            Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Avalanche |
+            AvalancheFuji | Celo | CeloAlfajores | CeloBaklava | Aurora | AuroraTestnet => {
+                SecurityModel::Sidechain
+            }
+
+            // Proof of Authority (Aura, Clique)
+            Poa | Sokol | Kovan | Rinkeby => SecurityModel::Consortium,
+            // Single-node development chains, controlled by whoever runs them
+            Dev | AnvilHardhat | MoonbeamDev => SecurityModel::Consortium,
+        }
+    }
+
+    /// Returns the delay before funds withdrawn from a rollup to its L1 can be claimed, if the
+    /// chain is a rollup with a known delay.
+    ///
+    /// This is ~7 days for optimistic rollups, the length of their challenge period.
+    pub const fn withdrawal_delay(&self) -> Option<Duration> {
+        match self.security_model() {
+            SecurityModel::OptimisticRollup => Some(Duration::from_secs(7 * 24 * 60 * 60)),
+            // depends on how often proofs are posted to L1
+            SecurityModel::ZkRollup => None,
+            SecurityModel::EthereumL1 | SecurityModel::Sidechain | SecurityModel::Consortium => {
+                None
+            }
+        }
+    }
+
+    /// Returns the number of decimals of the chain's gas token, which fee amounts in wei should be
+    /// scaled by when formatting them.
+    pub const fn fee_decimals(&self) -> u8 {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => 18,
+        }
+    }
+
+    /// Formats a fee amount in wei as a decimal amount of the chain's gas token, scaled by
+    /// [`fee_decimals`](Self::fee_decimals).
+    ///
+    /// ```
+    /// use ethers_core::types::{Chain, U256};
+    ///
+    /// let fee = U256::from(21_000u64) * U256::exp10(9);
+    /// assert_eq!(Chain::Mainnet.format_fee(fee), "0.000021000000000000");
+    /// ```
+    pub fn format_fee(&self, fee: U256) -> String {
+        format_fee_with_decimals(fee, self.fee_decimals())
+    }
+
+    /// Returns whether the chain is deprecated, i.e. no longer maintained and should not be used
+    /// for new deployments.
+    ///
+    /// Deprecated chains may still produce blocks, see [`is_shutdown`](Self::is_shutdown).
+    pub const fn is_deprecated(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Goerli | OptimismGoerli | ArbitrumGoerli | PolygonMumbai | Poa => true,
+
+            Morden | Ropsten | Rinkeby | Kovan | OptimismKovan | ArbitrumTestnet | Sokol => true,
+
+            Mainnet |
+            Sepolia |
+            Optimism |
+            Arbitrum |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            XDai |
+            Polygon |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain has been shut down and no longer produces blocks.
+    ///
+    /// Tooling should not wait for transactions to be mined on these chains.
+    pub const fn is_shutdown(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Morden | Ropsten | Rinkeby | Kovan | OptimismKovan | ArbitrumTestnet | Sokol => true,
+
+            Mainnet |
+            Goerli |
+            Sepolia |
+            Optimism |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns the RPC URL set in the environment for this chain, if any.
+    ///
+    /// The chain-specific `<CHAIN>_RPC_URL` variable is read first, where `<CHAIN>` is the chain's
+    /// name in `SCREAMING_SNAKE_CASE` (e.g. `POLYGON_RPC_URL`, `BSC_TESTNET_RPC_URL`), falling
+    /// back to the generic `ETH_RPC_URL` and `RPC_URL` variables.
+    pub fn rpc_url_from_env(&self) -> Option<String> {
+        self.rpc_url_from_vars(|var| std::env::var(var).ok())
+    }
+
+    /// Looks up the RPC URL like [`rpc_url_from_env`](Self::rpc_url_from_env), reading variables
+    /// through `get_var`.
+    fn rpc_url_from_vars(&self, get_var: impl Fn(&str) -> Option<String>) -> Option<String> {
+        [self.rpc_url_env_var().as_str(), "ETH_RPC_URL", "RPC_URL"]
+            .into_iter()
+            .find_map(|var| get_var(var).filter(|url| !url.is_empty()))
+    }
+
+    /// Returns the name of the chain-specific RPC URL environment variable, see
+    /// [`rpc_url_from_env`](Self::rpc_url_from_env).
+    fn rpc_url_env_var(&self) -> String {
+        format!("{}_RPC_URL", self.as_ref().to_uppercase().replace('-', "_"))
+    }
+
+    /// Returns the chain id to use when computing the [EIP-155](https://eips.ethereum.org/EIPS/eip-155)
+    /// `v` value of a signature, `v = chain_id * 2 + 35 + recovery_id`.
+    ///
+    /// This is the chain's own id for all known chains, and is the single place to add exceptions
+    /// for chains that sign with a different id.
+    pub const fn signing_chain_id(&self) -> u64 {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => *self as u64,
+        }
+    }
+
+    /// Returns whether the chain has activated [EIP-7623](https://eips.ethereum.org/EIPS/eip-7623),
+    /// which increases the gas cost floor of calldata-heavy transactions.
+    ///
+    /// It is part of Prague (Pectra), which has been activated on Ethereum mainnet, Sepolia, and
+    /// Gnosis (and its Chiado testnet).
+    pub const fn eip7623_active(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet | Sepolia | XDai | Chiado => true,
+
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain has activated [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702),
+    /// which adds set-code (type 4) transactions carrying an authorization list.
+    ///
+    /// It is part of Prague (Pectra), which has been activated on Ethereum mainnet, Sepolia, and
+    /// Gnosis (and its Chiado testnet).
+    pub const fn eip7702_active(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet | Sepolia | XDai | Chiado => true,
+
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain's `eth_estimateGas` accounts for the authorization list of an
+    /// [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702) transaction.
+    ///
+    /// Where this is `false`, the authorization list should be left out of the estimation request
+    /// and the result padded manually instead.
+    pub const fn estimate_gas_supports_auth_list(&self) -> bool {
+        self.eip7702_active()
+    }
+
+    /// Returns the block tag to query an account's transaction count at when fetching its next
+    /// nonce.
+    ///
+    /// This is [`Pending`](BlockNumber::Pending) where the chain's nodes reliably account for
+    /// transactions in their mempool, and [`Latest`](BlockNumber::Latest) otherwise.
+    pub const fn nonce_block_tag(&self) -> BlockNumber {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Chiado |
+            Polygon |
+            PolygonMumbai |
+            Avalanche |
+            AvalancheFuji |
+            Fantom |
+            FantomTestnet |
+            Dev |
+            AnvilHardhat => BlockNumber::Pending,
+
+            // Rollups have no public mempool, transactions are executed by the sequencer as soon
+            // as they are received
+            Optimism | OptimismKovan | OptimismGoerli | Arbitrum | ArbitrumTestnet |
+            ArbitrumGoerli | ArbitrumNova => BlockNumber::Latest,
+
+            // Unknown
+            Cronos | CronosTestnet | Rsk | Moonbeam | MoonbeamDev | Moonriver | Moonbase |
+            Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Celo | CeloAlfajores |
+            CeloBaklava | Aurora | AuroraTestnet => BlockNumber::Latest,
+        }
+    }
+
+    /// Returns the primary chain whose native currency has the given ticker, case-insensitively.
+    ///
+    /// Tickers shared by multiple chains map to the canonical L1, e.g. `ETH` maps to
+    /// [`Mainnet`](Chain::Mainnet) rather than to one of the rollups.
+    pub fn from_native_ticker(ticker: &str) -> Option<Chain> {
+        let chain = match ticker.to_ascii_uppercase().as_str() {
+            "ETH" => Chain::Mainnet,
+            "MATIC" | "POL" => Chain::Polygon,
+            "BNB" => Chain::BinanceSmartChain,
+            "AVAX" => Chain::Avalanche,
+            "FTM" => Chain::Fantom,
+            "XDAI" => Chain::XDai,
+            "CELO" => Chain::Celo,
+            "GLMR" => Chain::Moonbeam,
+            "MOVR" => Chain::Moonriver,
+            "CRO" => Chain::Cronos,
+            "EVMOS" => Chain::Evmos,
+            "ROSE" => Chain::Emerald,
+            "RBTC" => Chain::Rsk,
+            "POA" => Chain::Poa,
+            _ => return None,
+        };
+        Some(chain)
+    }
+
+    /// Returns the token in which the chain distributes block rewards or fee rebates to users, if
+    /// any.
+    ///
+    /// None of the known chains currently do.
+    pub const fn fee_rebate_token(&self) -> Option<Address> {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => None,
+        }
+    }
+
+    /// Returns whether the chain can be forked cleanly by Hardhat Network (`hardhat_reset` with a
+    /// fork URL).
+    ///
+    /// Chains with nonstandard transaction types or block structures are known to cause issues.
+    pub const fn hardhat_forkable(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Goerli |
+            Sepolia |
+            XDai |
+            Chiado |
+            Polygon |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Fantom |
+            FantomTestnet |
+            Dev |
+            AnvilHardhat => true,
+
+            // deposit transactions
+            Optimism | OptimismKovan | OptimismGoerli => false,
+            // custom transaction types and block structure
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => false,
+            // extra transaction fields
+            Celo | CeloAlfajores | CeloBaklava => false,
+
+            // Shut down
+            Morden | Ropsten | Rinkeby | Kovan | Sokol => false,
+
+            // Unknown
+            Cronos | CronosTestnet | Rsk | Poa | PolygonMumbai | Moonbeam | MoonbeamDev |
+            Moonriver | Moonbase | Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet |
+            Aurora | AuroraTestnet => false,
+        }
+    }
+
+    /// Returns how gas is accounted for on the chain, see [`GasSemantics`].
+    pub const fn gas_semantics(&self) -> GasSemantics {
+        use Chain::*;
+
+        match self {
+            Optimism | OptimismKovan | OptimismGoerli => GasSemantics::OptimismL2,
+
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => GasSemantics::ArbitrumL2,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => GasSemantics::Standard,
+        }
+    }
+
+    /// Returns the percentile of recent priority fees to use as the tip when estimating fees from
+    /// `eth_feeHistory`.
+    ///
+    /// This is the single-percentile counterpart of
+    /// [`default_reward_percentiles`](Self::default_reward_percentiles) for simpler oracles.
+    pub const fn tip_percentile(&self) -> f64 {
+        use Chain::*;
+
+        match self {
+            Mainnet | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Cronos | CronosTestnet |
+            Poa | Sokol | XDai | Chiado | Moonbeam | MoonbeamDev | Moonriver | Moonbase |
+            Evmos | EvmosTestnet | Avalanche | AvalancheFuji | Aurora | AuroraTestnet | Dev |
+            AnvilHardhat => 60.0,
+
+            // priority fees are very volatile, stick to the median
+            Polygon | PolygonMumbai => 50.0,
+
+            // legacy, there is no priority fee so this only applies to the gas price
+            Morden |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Fantom |
+            FantomTestnet |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava => 50.0,
+        }
+    }
+
+    /// Returns whether the chain's dominant client implements `eth_getProof`, which returns
+    /// Merkle-Patricia proofs of an account and its storage.
+    ///
+    /// **Note:** this is a best-effort hint, nodes may additionally only serve proofs for recent
+    /// blocks.
+    pub const fn supports_get_proof(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // geth and its forks, erigon, nethermind, reth, anvil
+            Mainnet |
+            Sepolia |
+            XDai |
+            Chiado |
+            Optimism |
+            Arbitrum |
+            ArbitrumNova |
+            Polygon |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Dev |
+            AnvilHardhat => true,
+
+            // Non-standard state trees (Substrate, Cosmos SDK, NEAR, ...)
+            Moonbeam | MoonbeamDev | Moonriver | Moonbase | Evmos | EvmosTestnet | Cronos |
+            CronosTestnet | Aurora | AuroraTestnet | Oasis | Emerald | EmeraldTestnet | Rsk => {
+                false
+            }
+
+            // Shut down, or deprecated
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | OptimismKovan | OptimismGoerli |
+            ArbitrumTestnet | ArbitrumGoerli | PolygonMumbai | Poa | Sokol => false,
+
+            // Unknown
+            Fantom | FantomTestnet | Avalanche | AvalancheFuji | Celo | CeloAlfajores |
+            CeloBaklava => false,
+        }
+    }
+
+    /// Returns the symbol of the chain's canonical wrapped native token, like `WETH` on mainnet
+    /// or `WMATIC` on Polygon, if there is one.
+    pub const fn wrapped_native_symbol(&self) -> Option<&'static str> {
+        use Chain::*;
+
+        let symbol = match self {
+            Mainnet | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Optimism | OptimismKovan |
+            OptimismGoerli | Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => "WETH",
+            Polygon | PolygonMumbai => "WMATIC",
+            BinanceSmartChain | BinanceSmartChainTestnet => "WBNB",
+            Avalanche | AvalancheFuji => "WAVAX",
+            Fantom | FantomTestnet => "WFTM",
+            XDai | Chiado => "WXDAI",
+            Moonbeam => "WGLMR",
+            Moonriver => "WMOVR",
+            Cronos | CronosTestnet => "WCRO",
+            Evmos | EvmosTestnet => "WEVMOS",
+            Emerald | EmeraldTestnet => "WROSE",
+            Rsk => "WRBTC",
+
+            // CELO is an ERC-20 token itself
+            Celo | CeloAlfajores | CeloBaklava => return None,
+
+            Morden | Poa | Sokol | Moonbase | MoonbeamDev | Dev | AnvilHardhat | Oasis |
+            Aurora | AuroraTestnet => return None,
+        };
+
+        Some(symbol)
+    }
+
+    /// Returns whether the chain has deterministic finality, i.e. a block cannot be reorged once
+    /// it has been produced, as is the case with BFT consensus.
+    ///
+    /// Transactions can be considered final after a single block on these chains, while chains
+    /// with probabilistic finality should wait for
+    /// [`safe_confirmation_blocks`](Self::safe_confirmation_blocks).
+    pub const fn has_deterministic_finality(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // Snowman, Lachesis, Tendermint, IBFT
+            Avalanche | AvalancheFuji | Fantom | FantomTestnet | Cronos | CronosTestnet |
+            Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Celo | CeloAlfajores |
+            CeloBaklava => true,
+
+            // single node
+            Dev | AnvilHardhat => true,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Chiado |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns the chain's average number of transactions per second, if known.
+    ///
+    /// **Note:** this is purely informational and only a rough figure derived from daily
+    /// transaction counts, not the chain's capacity.
+    pub const fn average_tps(&self) -> Option<u32> {
+        use Chain::*;
+
+        let tps = match self {
+            Mainnet => 13,
+            Optimism => 10,
+            Arbitrum => 20,
+            Polygon => 35,
+            BinanceSmartChain => 50,
+            Avalanche => 5,
+
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            OptimismKovan |
+            OptimismGoerli |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => return None,
+        };
+
+        Some(tps)
+    }
+
+    /// Returns a short, human readable explanation of why the chain is [legacy](Self::is_legacy),
+    /// or `None` if it supports EIP-1559.
+    pub const fn eip1559_unavailable_reason(&self) -> Option<&'static str> {
+        use Chain::*;
+
+        let reason = match self {
+            Optimism | OptimismKovan | OptimismGoerli => {
+                "Optimism uses a custom fee model with an additional L1 data fee"
+            }
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => {
+                "Arbitrum uses a custom fee model"
+            }
+            BinanceSmartChain | BinanceSmartChainTestnet => "BSC has no EIP-1559 base fee",
+            Fantom | FantomTestnet => "Fantom uses a custom fee model",
+            Rsk => "RSK does not support EIP-1559",
+            Oasis | Emerald | EmeraldTestnet => "Oasis does not support EIP-1559",
+            Celo | CeloAlfajores | CeloBaklava => "Celo uses its own transaction format",
+            Morden => "Morden was shut down before EIP-1559",
+
+            Mainnet | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Cronos | CronosTestnet |
+            Poa | Sokol | XDai | Polygon | PolygonMumbai | Moonbeam | MoonbeamDev | Moonriver |
+            Moonbase | Dev | AnvilHardhat | Evmos | EvmosTestnet | Chiado | Avalanche |
+            AvalancheFuji | Aurora | AuroraTestnet => return None,
+        };
+
+        Some(reason)
+    }
+
+    /// Returns whether the chain's dominant client implements `eth_maxPriorityFeePerGas`, which
+    /// returns a node-suggested priority fee.
+    ///
+    /// When unsupported, the priority fee should be estimated from `eth_feeHistory` instead.
+    ///
+    /// **Note:** this is a best-effort hint per client.
+    pub const fn supports_max_priority_fee_rpc(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // geth and its forks, erigon, nethermind, reth, anvil
+            Mainnet |
+            Sepolia |
+            XDai |
+            Chiado |
+            Optimism |
+            Arbitrum |
+            ArbitrumNova |
+            Polygon |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Dev |
+            AnvilHardhat => true,
+
+            // Shut down, or running clients that predate London
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | OptimismKovan | OptimismGoerli |
+            ArbitrumTestnet | ArbitrumGoerli | PolygonMumbai | Poa | Sokol | Rsk => false,
+
+            // Unknown
+            Cronos | CronosTestnet | Fantom | FantomTestnet | Moonbeam | MoonbeamDev |
+            Moonriver | Moonbase | Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet |
+            Celo | CeloAlfajores | CeloBaklava | Aurora | AuroraTestnet => false,
+        }
+    }
+
+    /// Returns how the chain's public RPC endpoints limit `eth_getLogs` queries, see
+    /// [`LogsPagination`].
+    pub const fn logs_pagination(&self) -> LogsPagination {
+        use Chain::*;
+
+        match self {
+            Avalanche | AvalancheFuji => LogsPagination::BlockRange(2_048),
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => LogsPagination::Unknown,
+        }
+    }
+
+    /// Returns the address of the contract on this rollup used to send messages to its L1: the
+    /// `L2CrossDomainMessenger` predeploy on OP-stack chains, and the `ArbSys` precompile on
+    /// Arbitrum chains.
+    ///
+    /// Returns `None` for chains which are not rollups.
+    pub const fn l2_messenger_address(&self) -> Option<Address> {
+        use Chain::*;
+
+        match self {
+            Optimism | OptimismKovan | OptimismGoerli => Some(OP_L2_CROSS_DOMAIN_MESSENGER_ADDRESS),
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => Some(ARB_SYS_ADDRESS),
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => None,
+        }
+    }
+
+    /// Returns the address of the contract on this rollup's L1 used to send messages to the
+    /// rollup: the `L1CrossDomainMessenger` for OP-stack chains, and the `Inbox` for Arbitrum
+    /// chains.
+    ///
+    /// Returns `None` for chains which are not rollups, or whose L1 contracts are not known.
+    pub const fn l1_messenger_address(&self) -> Option<Address> {
+        use Chain::*;
+
+        let address = match self {
+            // L1CrossDomainMessenger proxy
+            Optimism => H160([
+                0x25, 0xac, 0xe7, 0x1c, 0x97, 0xb3, 0x3c, 0xc4, 0x72, 0x9c, 0xf7, 0x72, 0xae, 0x26,
+                0x89, 0x34, 0xf7, 0xab, 0x5f, 0xa1,
+            ]),
+            // Inbox
+            Arbitrum => H160([
+                0x4d, 0xbd, 0x4f, 0xc5, 0x35, 0xac, 0x27, 0x20, 0x60, 0x64, 0xb6, 0x8f, 0xfc, 0xf8,
+                0x27, 0xb0, 0xa6, 0x0b, 0xab, 0x3f,
+            ]),
+            // Inbox
+            ArbitrumNova => H160([
+                0xc4, 0x44, 0x8b, 0x71, 0x11, 0x8c, 0x90, 0x71, 0xbc, 0xb9, 0x73, 0x4a, 0x0e, 0xac,
+                0x55, 0xd1, 0x8a, 0x15, 0x39, 0x49,
+            ]),
+
+            OptimismKovan | OptimismGoerli | ArbitrumTestnet | ArbitrumGoerli => return None,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => return None,
+        };
+
+        Some(address)
+    }
+
+    /// Returns a conservative gas price to fall back to when `eth_gasPrice` is unavailable, or
+    /// `None` if there is no sensible hardcoded default for the chain.
+    ///
+    /// **Note:** this is a last resort only, prefer querying the node wherever possible.
+    pub const fn default_gas_price(&self) -> Option<U256> {
+        use Chain::*;
+
+        let gwei = match self {
+            BinanceSmartChain => 5,
+            // Polygon enforces a minimum priority fee of 30 gwei
+            Polygon => 30,
+
+            // Fee markets are too volatile, or prices too far off gwei, for a static default
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => return None,
+        };
+
+        Some(U256([gwei * 1_000_000_000, 0, 0, 0]))
+    }
+
+    /// Returns whether the chain's nodes enforce [EIP-3607](https://eips.ethereum.org/EIPS/eip-3607),
+    /// i.e. reject transactions sent from an account that has code deployed.
+    ///
+    /// Local development nodes skip this check so that accounts with code can be impersonated.
+    pub const fn eip3607_active(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // geth, nethermind, erigon and their forks
+            Mainnet |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            XDai |
+            Chiado |
+            Polygon |
+            PolygonMumbai |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev => true,
+
+            AnvilHardhat => false,
+
+            // Shut down before EIP-3607 was introduced, or unknown
+            Morden | Kovan | Cronos | CronosTestnet | Rsk | Poa | Sokol | Fantom |
+            FantomTestnet | Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns which block number the chain reports for `block.number` (the `NUMBER` opcode), see
+    /// [`BlockNumberSemantics`].
+    pub const fn block_number_semantics(&self) -> BlockNumberSemantics {
+        use Chain::*;
+
+        match self {
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => {
+                BlockNumberSemantics::ArbitrumL1Reflected
+            }
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => BlockNumberSemantics::Native,
+        }
+    }
+
+    /// Returns whether the chain has activated [EIP-1108](https://eips.ethereum.org/EIPS/eip-1108),
+    /// which reduced the gas cost of the alt_bn128 precompiles (as part of Istanbul).
+    ///
+    /// This matters when estimating the cost of verifying zk-SNARK proofs on-chain.
+    pub const fn eip1108_active(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => true,
+
+            // Shut down before Istanbul
+            Morden => false,
+            // Prices the alt_bn128 precompiles independently
+            Rsk => false,
+        }
+    }
+
+    /// Returns a sensible default timeout for HTTP JSON-RPC requests to this chain.
+    ///
+    /// Local development nodes get a shorter timeout so that a stalled node is noticed quickly.
+    pub const fn recommended_rpc_timeout(&self) -> Duration {
+        use Chain::*;
+
+        let secs = match self {
+            Dev | AnvilHardhat => 10,
+
+            // Rsk's 30s blocks and congested public endpoints can make responses slow
+            Rsk => 60,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => 30,
+        };
+
+        Duration::from_secs(secs)
+    }
+
+    /// Returns the production chain of which this chain is a testnet, like
+    /// [`Mainnet`](Chain::Mainnet) for [`Sepolia`](Chain::Sepolia), or `None` if this is not a
+    /// testnet of a known chain.
+    pub const fn mainnet_counterpart(&self) -> Option<Chain> {
+        use Chain::*;
+
+        let mainnet = match self {
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | Sepolia => Mainnet,
+            OptimismKovan | OptimismGoerli => Optimism,
+            ArbitrumTestnet | ArbitrumGoerli => Arbitrum,
+            CronosTestnet => Cronos,
+            BinanceSmartChainTestnet => BinanceSmartChain,
+            Sokol => Poa,
+            Chiado => XDai,
+            PolygonMumbai => Polygon,
+            FantomTestnet => Fantom,
+            Moonbase => Moonbeam,
+            EvmosTestnet => Evmos,
+            EmeraldTestnet => Emerald,
+            AvalancheFuji => Avalanche,
+            CeloAlfajores | CeloBaklava => Celo,
+            AuroraTestnet => Aurora,
+
+            Mainnet | Optimism | Arbitrum | ArbitrumNova | Cronos | Rsk | BinanceSmartChain |
+            Poa | XDai | Polygon | Fantom | Moonbeam | MoonbeamDev | Moonriver | Dev |
+            AnvilHardhat | Evmos | Oasis | Emerald | Avalanche | Celo | Aurora => return None,
+        };
+
+        Some(mainnet)
+    }
+
+    /// Returns the known testnets of this chain, see
+    /// [`mainnet_counterpart`](Self::mainnet_counterpart).
+    pub fn testnets(&self) -> Vec<Chain> {
+        use strum::IntoEnumIterator;
+
+        Chain::iter().filter(|chain| chain.mainnet_counterpart() == Some(*self)).collect()
+    }
+
+    /// Returns whether this chain is a testnet of the `other` chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethers_core::types::Chain;
+    ///
+    /// assert!(Chain::OptimismGoerli.is_testnet_of(Chain::Optimism));
+    /// assert!(!Chain::OptimismGoerli.is_testnet_of(Chain::Mainnet));
+    /// ```
+    pub const fn is_testnet_of(&self, other: Chain) -> bool {
+        match self.mainnet_counterpart() {
+            Some(mainnet) => mainnet as u64 == other as u64,
+            None => false,
+        }
+    }
+
+    /// Returns whether the chain's transactions carry fields beyond the standard Ethereum ones,
+    /// which must be accounted for when RLP encoding or decoding them, see [`TxEncodingQuirks`].
+    pub const fn tx_encoding_quirks(&self) -> TxEncodingQuirks {
+        use Chain::*;
+
+        match self {
+            Celo | CeloAlfajores | CeloBaklava => TxEncodingQuirks::Celo,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Aurora |
+            AuroraTestnet => TxEncodingQuirks::Standard,
+        }
+    }
+
+    /// Returns the maximum number of blocks that should be requested in a single `eth_feeHistory`
+    /// call on this chain.
+    ///
+    /// Defaults to a conservative 128 where the limit is unknown.
+    pub const fn max_fee_history_block_count(&self) -> u64 {
+        use Chain::*;
+
+        match self {
+            // geth, erigon, nethermind and their forks cap requests at 1024 blocks
+            Mainnet |
+            Sepolia |
+            Optimism |
+            Arbitrum |
+            ArbitrumNova |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            XDai |
+            Chiado |
+            Polygon |
+            Dev |
+            AnvilHardhat => 1024,
+
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | OptimismKovan | OptimismGoerli |
+            ArbitrumTestnet | ArbitrumGoerli | Cronos | CronosTestnet | Rsk | Poa | Sokol |
+            PolygonMumbai | Fantom | FantomTestnet | Moonbeam | MoonbeamDev | Moonriver |
+            Moonbase | Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Avalanche |
+            AvalancheFuji | Celo | CeloAlfajores | CeloBaklava | Aurora | AuroraTestnet => 128,
+        }
+    }
+
+    /// Returns whether the chain is a test network, including local development chains.
+    pub const fn is_testnet(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // Ethereum testnets
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | Sepolia => true,
+
+            // L2 and sidechain testnets
+            OptimismGoerli |
+            OptimismKovan |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            PolygonMumbai |
+            FantomTestnet |
+            BinanceSmartChainTestnet |
+            CronosTestnet |
+            AvalancheFuji |
+            CeloAlfajores |
+            CeloBaklava |
+            EmeraldTestnet |
+            EvmosTestnet |
+            AuroraTestnet |
+            Chiado |
+            Moonbase |
+            MoonbeamDev |
+            Sokol => true,
+
+            // Local development chains
+            Dev | AnvilHardhat => true,
+
+            Mainnet | Optimism | Arbitrum | ArbitrumNova | Cronos | Rsk | BinanceSmartChain |
+            Poa | XDai | Polygon | Fantom | Moonbeam | Moonriver | Evmos | Oasis | Emerald |
+            Avalanche | Celo | Aurora => false,
+        }
+    }
+
+    /// Returns whether the chain is a local development chain, like Anvil or Hardhat.
+    pub const fn is_local(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Dev | AnvilHardhat => true,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Chiado |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Evmos |
+            EvmosTestnet |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain is a production network, i.e. neither a test network nor a local
+    /// development chain.
+    pub const fn is_mainnet(&self) -> bool {
+        !self.is_testnet() && !self.is_local()
+    }
+
+    /// Returns the [EIP-2124](https://eips.ethereum.org/EIPS/eip-2124) fork hash of the chain's
+    /// current fork, as advertised in the devp2p `eth` handshake, or `None` if it is not known.
+    ///
+    /// **Note:** this reflects the latest fork known to this crate (BPO2), and must be updated
+    /// whenever a new fork activates.
+    pub const fn fork_id(&self) -> Option<[u8; 4]> {
+        use Chain::*;
+
+        let hash = match self {
+            Mainnet => [0x07, 0xc9, 0x46, 0x2e],
+            Sepolia => [0x26, 0x89, 0x56, 0xb6],
+
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => return None,
+        };
+
+        Some(hash)
+    }
+
+    /// Returns the ticker of the chain's native currency, i.e. the one gas is paid in.
+    pub const fn native_currency_symbol(&self) -> &'static str {
+        use Chain::*;
+
+        match self {
+            Mainnet | Morden | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Optimism |
+            OptimismKovan | OptimismGoerli | Arbitrum | ArbitrumTestnet | ArbitrumGoerli |
+            ArbitrumNova | Aurora | AuroraTestnet | Dev | AnvilHardhat => "ETH",
+            Polygon | PolygonMumbai => "MATIC",
+            BinanceSmartChain | BinanceSmartChainTestnet => "BNB",
+            Avalanche | AvalancheFuji => "AVAX",
+            Fantom | FantomTestnet => "FTM",
+            XDai | Chiado => "xDAI",
+            Celo | CeloAlfajores | CeloBaklava => "CELO",
+            Moonbeam => "GLMR",
+            Moonriver => "MOVR",
+            Moonbase | MoonbeamDev => "DEV",
+            Cronos | CronosTestnet => "CRO",
+            Evmos | EvmosTestnet => "EVMOS",
+            Oasis | Emerald | EmeraldTestnet => "ROSE",
+            Rsk => "RBTC",
+            Poa => "POA",
+            Sokol => "SPOA",
+        }
+    }
+
+    /// Returns whether the given chain id should not be used for a production network: `0`, the
+    /// ids used by local development nodes, and ids above the
+    /// [EIP-2294](https://eips.ethereum.org/EIPS/eip-2294) bound.
+    pub const fn is_reserved_chain_id(id: u64) -> bool {
+        // floor(MAX_UINT64 / 2) - 36, the largest id whose EIP-155 `v` fits into a u64
+        const MAX_CHAIN_ID: u64 = u64::MAX / 2 - 36;
+
+        match id {
+            0 => true,
+            id if id == Chain::Dev as u64 || id == Chain::AnvilHardhat as u64 => true,
+            id => id > MAX_CHAIN_ID,
+        }
+    }
+
+    /// Returns how often a gas oracle should refresh its estimate on this chain, roughly once per
+    /// block and at most once per second.
+    ///
+    /// Falls back to 12 seconds if the chain's [average blocktime](Self::average_blocktime_hint)
+    /// is unknown.
+    pub const fn gas_oracle_refresh_interval(&self) -> Duration {
+        const MIN_INTERVAL_MS: u64 = 1_000;
+
+        let ms = match self.average_blocktime_hint() {
+            Some(blocktime) => blocktime.as_millis() as u64,
+            None => 12_000,
+        };
+
+        Duration::from_millis(if ms < MIN_INTERVAL_MS { MIN_INTERVAL_MS } else { ms })
+    }
+
+    /// Returns the address of the chain's canonical wrapped native token, like
+    /// [`WETH`](https://etherscan.io/address/0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2) on
+    /// mainnet, if it is well established.
+    ///
+    /// See also [`wrapped_native_symbol`](Self::wrapped_native_symbol).
+    pub const fn wrapped_native_token(&self) -> Option<Address> {
+        use Chain::*;
+
+        let address = match self {
+            // WETH: 0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2
+            Mainnet => H160([
+                0xc0, 0x2a, 0xaa, 0x39, 0xb2, 0x23, 0xfe, 0x8d, 0x0a, 0x0e, 0x5c, 0x4f, 0x27, 0xea,
+                0xd9, 0x08, 0x3c, 0x75, 0x6c, 0xc2,
+            ]),
+            // WETH: 0xB4FBF271143F4FBf7B91A5ded31805e42b2208d6
+            Goerli => H160([
+                0xb4, 0xfb, 0xf2, 0x71, 0x14, 0x3f, 0x4f, 0xbf, 0x7b, 0x91, 0xa5, 0xde, 0xd3, 0x18,
+                0x05, 0xe4, 0x2b, 0x22, 0x08, 0xd6,
+            ]),
+            // WETH: 0xfFf9976782d46CC05630D1f6eBAb18b2324d6B14
+            Sepolia => H160([
+                0xff, 0xf9, 0x97, 0x67, 0x82, 0xd4, 0x6c, 0xc0, 0x56, 0x30, 0xd1, 0xf6, 0xeb, 0xab,
+                0x18, 0xb2, 0x32, 0x4d, 0x6b, 0x14,
+            ]),
+            // WETH predeploy: 0x4200000000000000000000000000000000000006
+            Optimism | OptimismGoerli => H160([
+                0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x06,
+            ]),
+            // WETH: 0x82aF49447D8a07e3bd95BD0d56f35241523fBab1
+            Arbitrum => H160([
+                0x82, 0xaf, 0x49, 0x44, 0x7d, 0x8a, 0x07, 0xe3, 0xbd, 0x95, 0xbd, 0x0d, 0x56, 0xf3,
+                0x52, 0x41, 0x52, 0x3f, 0xba, 0xb1,
+            ]),
+            // WMATIC: 0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270
+            Polygon => H160([
+                0x0d, 0x50, 0x0b, 0x1d, 0x8e, 0x8e, 0xf3, 0x1e, 0x21, 0xc9, 0x9d, 0x1d, 0xb9, 0xa6,
+                0x44, 0x4d, 0x3a, 0xdf, 0x12, 0x70,
+            ]),
+            // WMATIC: 0x9c3C9283D3e44854697Cd22D3Faa240Cfb032889
+            PolygonMumbai => H160([
+                0x9c, 0x3c, 0x92, 0x83, 0xd3, 0xe4, 0x48, 0x54, 0x69, 0x7c, 0xd2, 0x2d, 0x3f, 0xaa,
+                0x24, 0x0c, 0xfb, 0x03, 0x28, 0x89,
+            ]),
+            // WBNB: 0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c
+            BinanceSmartChain => H160([
+                0xbb, 0x4c, 0xdb, 0x9c, 0xbd, 0x36, 0xb0, 0x1b, 0xd1, 0xcb, 0xae, 0xbf, 0x2d, 0xe0,
+                0x8d, 0x91, 0x73, 0xbc, 0x09, 0x5c,
+            ]),
+            // WAVAX: 0xB31f66AA3C1e785363F0875A1B74E27b85FD66c7
+            Avalanche => H160([
+                0xb3, 0x1f, 0x66, 0xaa, 0x3c, 0x1e, 0x78, 0x53, 0x63, 0xf0, 0x87, 0x5a, 0x1b, 0x74,
+                0xe2, 0x7b, 0x85, 0xfd, 0x66, 0xc7,
+            ]),
+            // WFTM: 0x21be370D5312f44cB42ce377BC9b8a0cEF1A4C83
+            Fantom => H160([
+                0x21, 0xbe, 0x37, 0x0d, 0x53, 0x12, 0xf4, 0x4c, 0xb4, 0x2c, 0xe3, 0x77, 0xbc, 0x9b,
+                0x8a, 0x0c, 0xef, 0x1a, 0x4c, 0x83,
+            ]),
+            // WXDAI: 0xe91D153E0b41518A2Ce8Dd3D7944Fa863463a97d
+            XDai => H160([
+                0xe9, 0x1d, 0x15, 0x3e, 0x0b, 0x41, 0x51, 0x8a, 0x2c, 0xe8, 0xdd, 0x3d, 0x79, 0x44,
+                0xfa, 0x86, 0x34, 0x63, 0xa9, 0x7d,
+            ]),
+
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Kovan |
+            OptimismKovan |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => return None,
+        };
+
+        Some(address)
+    }
+
+    /// Returns whether [ERC-1271](https://eips.ethereum.org/EIPS/eip-1271) signatures, verified by
+    /// a `STATICCALL` to the signer's `isValidSignature`, can be used on the chain.
+    pub const fn supports_erc1271(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => true,
+
+            // Shut down before Byzantium introduced `STATICCALL`
+            Morden => false,
+        }
+    }
+
+    /// Returns the address of the chain's [Multicall3](https://github.com/mds1/multicall)
+    /// deployment, if it is known to be deployed.
+    ///
+    /// All known deployments are at the canonical address
+    /// `0xcA11bde05977b3631167028862bE2a173976CA11`.
+    pub const fn multicall3_address(&self) -> Option<Address> {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            Cronos |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            XDai |
+            Chiado |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            Moonriver |
+            Moonbase |
+            Evmos |
+            EvmosTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Aurora => Some(MULTICALL3_ADDRESS),
+
+            Morden | ArbitrumNova | CronosTestnet | Poa | Sokol | MoonbeamDev | Dev |
+            AnvilHardhat | Oasis | Emerald | EmeraldTestnet | Celo | CeloAlfajores |
+            CeloBaklava | AuroraTestnet => None,
+        }
+    }
+
+    /// Returns the URL of the chain's explorer search page for the given query, which may be
+    /// anything the explorer can search for, like a transaction hash, an address or an ENS name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethers_core::types::Chain;
+    ///
+    /// assert_eq!(
+    ///     Chain::Mainnet.explorer_search_url("vitalik.eth").unwrap(),
+    ///     "https://etherscan.io/search?q=vitalik.eth"
+    /// );
+    /// ```
+    pub fn explorer_search_url(&self, query: &str) -> Option<String> {
+        let (_, base) = self.explorer_urls()?;
+        let query = url::form_urlencoded::byte_serialize(query.as_bytes()).collect::<String>();
+        Some(format!("{}/search?q={query}", base.trim_end_matches('/')))
+    }
+
+    /// Returns the [EIP-712](https://eips.ethereum.org/EIPS/eip-712) domain `version` to use when
+    /// a protocol does not specify one.
+    ///
+    /// This is `"1"` on all known chains.
+    pub const fn default_eip712_domain_version(&self) -> &'static str {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => "1",
+        }
+    }
+
+    /// Returns the name of the environment variable that conventionally holds the API key for the
+    /// chain's Etherscan-like explorer, like `ETHERSCAN_API_KEY`.
+    ///
+    /// Returns `None` if the chain has no such explorer, or if its explorer does not require an
+    /// API key, like Blockscout.
+    pub const fn etherscan_api_key_name(&self) -> Option<&'static str> {
+        use Chain::*;
+
+        let name = match self {
+            Mainnet | Ropsten | Rinkeby | Goerli | Kovan | Sepolia => "ETHERSCAN_API_KEY",
+            Optimism | OptimismKovan | OptimismGoerli => "OPTIMISTIC_ETHERSCAN_API_KEY",
+            Arbitrum | ArbitrumTestnet | ArbitrumNova => "ARBISCAN_API_KEY",
+            Polygon | PolygonMumbai => "POLYGONSCAN_API_KEY",
+            BinanceSmartChain | BinanceSmartChainTestnet => "BSCSCAN_API_KEY",
+            Avalanche | AvalancheFuji => "SNOWTRACE_API_KEY",
+            Fantom | FantomTestnet => "FTMSCAN_API_KEY",
+            Cronos | CronosTestnet => "CRONOSCAN_API_KEY",
+            Moonbeam | Moonriver | Moonbase => "MOONSCAN_API_KEY",
+            Aurora | AuroraTestnet => "AURORASCAN_API_KEY",
+
+            // Blockscout
+            ArbitrumGoerli | Rsk | Poa | Sokol | XDai | Chiado | Evmos | EvmosTestnet | Oasis |
+            Emerald | EmeraldTestnet | Celo | CeloAlfajores | CeloBaklava => return None,
+
+            Morden | MoonbeamDev | Dev | AnvilHardhat => return None,
+        };
+
+        Some(name)
+    }
+
+    /// Returns whether the chain has activated [EIP-3541](https://eips.ethereum.org/EIPS/eip-3541)
+    /// (as part of London), which rejects deploying contracts whose code starts with the `0xEF`
+    /// byte.
+    pub const fn eip3541_active(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            OptimismGoerli |
+            Optimism |
+            Arbitrum |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            XDai |
+            Chiado |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava => true,
+
+            // Shut down before London
+            Morden | OptimismKovan | ArbitrumTestnet => false,
+
+            // Unknown
+            Rsk | Poa | Sokol | Oasis | Emerald | EmeraldTestnet | Aurora | AuroraTestnet => false,
+        }
+    }
+
+    /// Returns the address of Circle's natively issued USDC on the chain, if there is one.
+    ///
+    /// Bridged versions of USDC, like `USDC.e`, are not considered.
+    pub const fn usdc_address(&self) -> Option<Address> {
+        use Chain::*;
+
+        let address = match self {
+            // 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48
+            Mainnet => H160([
+                0xa0, 0xb8, 0x69, 0x91, 0xc6, 0x21, 0x8b, 0x36, 0xc1, 0xd1, 0x9d, 0x4a, 0x2e, 0x9e,
+                0xb0, 0xce, 0x36, 0x06, 0xeb, 0x48,
+            ]),
+            // 0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238
+            Sepolia => H160([
+                0x1c, 0x7d, 0x4b, 0x19, 0x6c, 0xb0, 0xc7, 0xb0, 0x1d, 0x74, 0x3f, 0xbc, 0x61, 0x16,
+                0xa9, 0x02, 0x37, 0x9c, 0x72, 0x38,
+            ]),
+            // 0x0b2C639c533813f4Aa9D7837CAf62653d097Ff85
+            Optimism => H160([
+                0x0b, 0x2c, 0x63, 0x9c, 0x53, 0x38, 0x13, 0xf4, 0xaa, 0x9d, 0x78, 0x37, 0xca, 0xf6,
+                0x26, 0x53, 0xd0, 0x97, 0xff, 0x85,
+            ]),
+            // 0xaf88d065e77c8cC2239327C5EDb3A432268e5831
+            Arbitrum => H160([
+                0xaf, 0x88, 0xd0, 0x65, 0xe7, 0x7c, 0x8c, 0xc2, 0x23, 0x93, 0x27, 0xc5, 0xed, 0xb3,
+                0xa4, 0x32, 0x26, 0x8e, 0x58, 0x31,
+            ]),
+            // 0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359
+            Polygon => H160([
+                0x3c, 0x49, 0x9c, 0x54, 0x2c, 0xef, 0x5e, 0x38, 0x11, 0xe1, 0x19, 0x2c, 0xe7, 0x0d,
+                0x8c, 0xc0, 0x3d, 0x5c, 0x33, 0x59,
+            ]),
+            // 0xB97EF9Ef8734C71904D8002F8b6Bc66Dd9c48a6E
+            Avalanche => H160([
+                0xb9, 0x7e, 0xf9, 0xef, 0x87, 0x34, 0xc7, 0x19, 0x04, 0xd8, 0x00, 0x2f, 0x8b, 0x6b,
+                0xc6, 0x6d, 0xd9, 0xc4, 0x8a, 0x6e,
+            ]),
+            // 0x5425890298aed601595a70AB815c96711a31Bc65
+            AvalancheFuji => H160([
+                0x54, 0x25, 0x89, 0x02, 0x98, 0xae, 0xd6, 0x01, 0x59, 0x5a, 0x70, 0xab, 0x81, 0x5c,
+                0x96, 0x71, 0x1a, 0x31, 0xbc, 0x65,
+            ]),
+            // 0xcebA9300f2b948710d2653dD7B07f33A8B32118C
+            Celo => H160([
+                0xce, 0xba, 0x93, 0x00, 0xf2, 0xb9, 0x48, 0x71, 0x0d, 0x26, 0x53, 0xdd, 0x7b, 0x07,
+                0xf3, 0x3a, 0x8b, 0x32, 0x11, 0x8c,
+            ]),
+
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            OptimismKovan |
+            OptimismGoerli |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => return None,
+        };
+
+        Some(address)
+    }
+
+    /// Returns the URL of the given transaction on the chain's explorer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ethers_core::types::{Chain, H256};
+    ///
+    /// let url = Chain::Mainnet.explorer_tx_url(H256::zero()).unwrap();
+    /// assert_eq!(
+    ///     url.as_str(),
+    ///     "https://etherscan.io/tx/0x0000000000000000000000000000000000000000000000000000000000000000"
+    /// );
+    /// ```
+    pub fn explorer_tx_url(&self, hash: H256) -> Option<Url> {
+        self.explorer_page_url(&format!("tx/{hash:?}"))
+    }
+
+    /// Returns the URL of the given address on the chain's explorer.
+    pub fn explorer_address_url(&self, address: Address) -> Option<Url> {
+        self.explorer_page_url(&format!("address/{address:?}"))
+    }
+
+    /// Returns the URL of the given token on the chain's explorer.
+    pub fn explorer_token_url(&self, token: Address) -> Option<Url> {
+        self.explorer_page_url(&format!("token/{token:?}"))
+    }
+
+    /// Returns the URL of the given page relative to the explorer's base URL, which may itself
+    /// have a path, like Blockscout's `/xdai/mainnet`.
+    fn explorer_page_url(&self, page: &str) -> Option<Url> {
+        let (_, base) = self.explorer_urls()?;
+        let url = format!("{}/{page}", base.trim_end_matches('/'));
+        Some(Url::parse(&url).expect("valid explorer URL"))
+    }
+
+    /// Returns whether including an [EIP-2930](https://eips.ethereum.org/EIPS/eip-2930) access
+    /// list in a transaction can reduce its gas cost on the chain, i.e. whether the chain prices
+    /// state access according to [EIP-2929](https://eips.ethereum.org/EIPS/eip-2929) (Berlin).
+    ///
+    /// Where this is `false`, generating an access list is wasted work.
+    pub const fn access_list_affects_gas(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            OptimismGoerli |
+            Optimism |
+            Arbitrum |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            XDai |
+            Chiado |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava => true,
+
+            // Shut down before Berlin
+            Morden | OptimismKovan | ArbitrumTestnet => false,
+
+            // Unknown
+            Rsk | Poa | Sokol | Oasis | Emerald | EmeraldTestnet | Aurora | AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain is a rollup, i.e. an L2 which posts its transaction data to
+    /// Ethereum.
+    ///
+    /// See also [`is_optimistic_rollup`](Self::is_optimistic_rollup) and
+    /// [`is_arbitrum`](Self::is_arbitrum).
+    pub const fn is_rollup(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Optimism | OptimismKovan | OptimismGoerli | Arbitrum | ArbitrumTestnet |
+            ArbitrumGoerli | ArbitrumNova => true,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain is an OP-stack optimistic rollup, like Optimism.
+    ///
+    /// Arbitrum chains are optimistic rollups as well, but are classified by
+    /// [`is_arbitrum`](Self::is_arbitrum) instead.
+    pub const fn is_optimistic_rollup(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Optimism | OptimismKovan | OptimismGoerli => true,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain is built on the Arbitrum stack.
+    pub const fn is_arbitrum(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => true,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns the known chain with the given id, or `None` if the id is unknown.
+    ///
+    /// This is the strict counterpart of [`ChainId::from`], which accepts any id.
+    pub fn try_known_from(id: u64) -> Option<Chain> {
+        Chain::try_from(id).ok()
+    }
+
+    /// Returns the chain's [average blocktime](Self::average_blocktime_hint) in seconds, if known.
+    pub fn average_blocktime_secs(&self) -> Option<f64> {
+        self.average_blocktime_hint().map(|blocktime| blocktime.as_secs_f64())
+    }
+
+    /// Returns whether the chain accepts both legacy and EIP-1559 (type 2) transactions, in which
+    /// case a transaction builder can fall back from one to the other.
+    ///
+    /// **Note:** this can be `true` for chains which are [`is_legacy`](Self::is_legacy), where
+    /// EIP-1559 transactions are accepted but offer no benefit over legacy ones.
+    pub const fn accepts_both_tx_types(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            XDai |
+            Chiado |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Avalanche |
+            AvalancheFuji => true,
+
+            // EIP-1559, see `is_legacy`
+            Cronos | CronosTestnet | Poa | Sokol | Aurora | AuroraTestnet => true,
+
+            // Legacy transactions only
+            Morden | OptimismKovan | ArbitrumTestnet | Rsk | Oasis | Emerald | EmeraldTestnet => {
+                false
+            }
+
+            // Celo's fee abstraction uses its own transaction types
+            Celo | CeloAlfajores | CeloBaklava => false,
+        }
+    }
+
+    /// Returns whether the chain's sequencer or block producers accept
+    /// `eth_sendRawTransactionConditional`, which only includes a transaction if the given state
+    /// conditions hold at inclusion time.
+    ///
+    /// **Note:** this is a best-effort hint, support depends on the sequencer's or RPC provider's
+    /// configuration.
+    pub const fn supports_conditional_raw_tx(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // Nitro sequencer and bor
+            Arbitrum | ArbitrumNova | Polygon => true,
+
+            // op-geth implements the method, but it is disabled on the public sequencers
+            Optimism | OptimismKovan | OptimismGoerli => false,
+
+            Mainnet |
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns the address of the [ENS](https://docs.ens.domains/) registry on the chain, if ENS
+    /// is deployed on it.
+    ///
+    /// Only the canonical ENS deployments are considered, ENS-compatible registries on other
+    /// chains are not.
+    pub const fn ens_registry_address(&self) -> Option<Address> {
+        use Chain::*;
+
+        match self {
+            Mainnet | Ropsten | Rinkeby | Goerli | Sepolia => Some(ENS_REGISTRY_ADDRESS),
+
+            Morden |
+            Kovan |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Chiado |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => None,
+        }
+    }
+
+    /// Returns an iterator over all chains which have an Etherscan-like explorer, together with
+    /// its URLs.
+    pub fn iter_with_explorer() -> impl Iterator<Item = (Chain, EtherscanUrls)> {
+        use strum::IntoEnumIterator;
+
+        Chain::iter().filter_map(|chain| {
+            let api = chain.etherscan_api_url()?;
+            let base = chain.etherscan_base_url()?;
+            Some((chain, EtherscanUrls { api, base }))
+        })
+    }
+
+    /// Returns an iterator over all production [rollups](Self::is_rollup), i.e. excluding their
+    /// [testnets](Self::is_testnet).
+    pub fn iter_production_rollups() -> impl Iterator<Item = Chain> {
+        use strum::IntoEnumIterator;
+
+        Chain::iter().filter(|chain| chain.is_rollup() && !chain.is_testnet())
+    }
+
+    /// Returns whether the chain has activated [EIP-6110](https://eips.ethereum.org/EIPS/eip-6110)
+    /// (as part of Prague), whose deposit requests are committed to by the block header's
+    /// `requestsHash`.
+    pub const fn supports_deposit_requests(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet | Sepolia | XDai | Chiado => true,
+
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain has activated Shanghai, and with it the `PUSH0` opcode of
+    /// [EIP-3855](https://eips.ethereum.org/EIPS/eip-3855).
+    ///
+    /// Contracts compiled for Shanghai or later (the default since solc 0.8.20) cannot be deployed
+    /// on chains where this is `false`. Chains for which activation is unknown default to `false`.
+    ///
+    /// **Note:** this is a snapshot of the chains' upgrades at the time of writing.
+    pub const fn supports_push0(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // Shapella
+            Mainnet | Goerli | Sepolia | XDai | Chiado | Dev | AnvilHardhat => true,
+            // Canyon
+            Optimism | OptimismGoerli => true,
+            // ArbOS 11
+            Arbitrum | ArbitrumNova => true,
+            // Napoli
+            Polygon => true,
+            // Kepler
+            BinanceSmartChain | BinanceSmartChainTestnet => true,
+            // Durango
+            Avalanche | AvalancheFuji => true,
+            // OP-stack L2 migration
+            Celo | CeloAlfajores => true,
+
+            // Shut down before activating Shanghai
+            Morden | Ropsten | Rinkeby | Kovan | OptimismKovan | ArbitrumTestnet => false,
+
+            // Unknown
+            ArbitrumGoerli | Cronos | CronosTestnet | Rsk | Poa | Sokol | PolygonMumbai |
+            Fantom | FantomTestnet | Moonbeam | MoonbeamDev | Moonriver | Moonbase | Evmos |
+            EvmosTestnet | Oasis | Emerald | EmeraldTestnet | CeloBaklava | Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain has activated Shanghai, see
+    /// [`supports_push0`](Self::supports_push0).
+    pub const fn supports_shanghai(&self) -> bool {
+        self.supports_push0()
+    }
+
+    /// Returns the number of decimal places to show when formatting a gas price on this chain in
+    /// gwei, so that typical prices on cheap chains don't round to `0 gwei`.
+    pub const fn gas_price_display_precision(&self) -> u8 {
+        use Chain::*;
+
+        match self {
+            // Tens to thousands of gwei
+            Polygon | PolygonMumbai | Fantom | FantomTestnet | Cronos | CronosTestnet |
+            Moonbeam | MoonbeamDev | Moonriver | Moonbase | Evmos | EvmosTestnet => 0,
+
+            Mainnet | Morden | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Avalanche |
+            AvalancheFuji | Celo | CeloAlfajores | CeloBaklava => 1,
+
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Chiado |
+            Rsk |
+            Dev |
+            AnvilHardhat |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Aurora |
+            AuroraTestnet => 2,
+
+            // Hundredths to thousandths of a gwei
+            Optimism | OptimismKovan | OptimismGoerli | Arbitrum | ArbitrumTestnet |
+            ArbitrumGoerli | ArbitrumNova => 4,
+        }
+    }
+
+    /// Returns whether the chain accepts [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844) blob
+    /// (type 3) transactions, i.e. whether it has activated Cancun.
+    ///
+    /// Chains for which activation is unknown default to `false`.
+    ///
+    /// **Note:** this is a snapshot of the chains' upgrades as of October 2025.
+    pub const fn supports_blob_transactions(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // Dencun
+            Mainnet | Goerli | Sepolia | Dev | AnvilHardhat => true,
+            // Dencun, with Gnosis' own blob parameters
+            XDai | Chiado => true,
+            // Haber (BEP-336)
+            BinanceSmartChain => true,
+            // Tycho (BEP-336)
+            BinanceSmartChainTestnet => true,
+
+            // L2s accept blobs on L1 but not as transactions on L2 itself
+            Optimism | OptimismKovan | OptimismGoerli | Arbitrum | ArbitrumTestnet |
+            ArbitrumGoerli | ArbitrumNova => false,
+
+            Morden | Ropsten | Rinkeby | Kovan | Cronos | CronosTestnet | Rsk | Poa | Sokol |
+            Polygon | PolygonMumbai | Fantom | FantomTestnet | Moonbeam | MoonbeamDev |
+            Moonriver | Moonbase | Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet |
+            Avalanche | AvalancheFuji | Celo | CeloAlfajores | CeloBaklava | Aurora |
+            AuroraTestnet => false,
+        }
+    }
+
+    /// Returns the number of confirmations to wait for before treating a transaction as final by
+    /// default, like in `PendingTransaction::confirmations`.
+    ///
+    /// This is the same as [`safe_confirmation_blocks`](Self::safe_confirmation_blocks).
+    pub const fn recommended_confirmations(&self) -> u64 {
+        self.safe_confirmation_blocks()
+    }
+
+    /// Returns a rough hint of the standard deviation of the chain's blocktime around its
+    /// [average](Self::average_blocktime_hint), or `None` if unknown.
+    ///
+    /// Chains with fixed block slots have a low variance, while chains that produce blocks on
+    /// demand have a high one, so pollers may want to widen their interval accordingly.
+    pub const fn blocktime_variance(&self) -> Option<Duration> {
+        use Chain::*;
+
+        let ms = match self {
+            // Fixed slots, with the occasional skipped slot
+            Mainnet | Sepolia => 1_000,
+            Polygon |
+            PolygonMumbai |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            XDai |
+            Chiado => 200,
+
+            // Blocks are produced on demand
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => 500,
+            Avalanche | AvalancheFuji => 1_000,
+            Fantom | FantomTestnet => 500,
+
+            Morden | Ropsten | Rinkeby | Goerli | Kovan | Optimism | OptimismKovan |
+            OptimismGoerli | Cronos | CronosTestnet | Rsk | Poa | Sokol | Moonbeam |
+            MoonbeamDev | Moonriver | Moonbase | Dev | AnvilHardhat | Evmos | EvmosTestnet |
+            Oasis | Emerald | EmeraldTestnet | Celo | CeloAlfajores | CeloBaklava | Aurora |
+            AuroraTestnet => return None,
+        };
+
+        Some(Duration::from_millis(ms))
+    }
+
+    /// Returns whether the chain has activated Cancun's execution-layer changes, including the
+    /// `MCOPY`, `TSTORE` and `TLOAD` opcodes.
+    ///
+    /// Unlike [`supports_blob_transactions`](Self::supports_blob_transactions), this is also
+    /// `true` for L2s that adopted the Cancun EVM without accepting blob transactions. Likewise,
+    /// only chains with a beacon chain also
+    /// [expose its block roots](Self::supports_beacon_block_root). Chains for which activation is
+    /// unknown default to `false`.
+    ///
+    /// **Note:** this is a snapshot of the chains' upgrades as of October 2025.
+    pub const fn supports_cancun(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            // Dencun
+            Mainnet | Goerli | Sepolia | XDai | Chiado | Dev | AnvilHardhat => true,
+            // Ecotone
+            Optimism => true,
+            // ArbOS 20
+            Arbitrum | ArbitrumNova => true,
+            // Napoli
+            Polygon => true,
+            // Haber (BEP-336)
+            BinanceSmartChain => true,
+            // Tycho (BEP-336)
+            BinanceSmartChainTestnet => true,
+            // Etna
+            Avalanche | AvalancheFuji => true,
+            // OP-stack L2 migration
+            Celo | CeloAlfajores => true,
+
+            // Shut down before activating Cancun
+            Morden | Ropsten | Rinkeby | Kovan | OptimismKovan | ArbitrumTestnet => false,
+
+            // Unknown
+            OptimismGoerli | ArbitrumGoerli | Cronos | CronosTestnet | Rsk | Poa | Sokol |
+            PolygonMumbai | Fantom | FantomTestnet | Moonbeam | MoonbeamDev | Moonriver |
+            Moonbase | Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | CeloBaklava |
+            Aurora | AuroraTestnet => false,
+        }
+    }
+
+    /// Returns whether the chain has activated [EIP-1344](https://eips.ethereum.org/EIPS/eip-1344)
+    /// (as part of Istanbul), which adds the `CHAINID` opcode.
+    pub const fn eip1344_active(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet |
+            Ropsten |
+            Rinkeby |
+            Goerli |
+            Kovan |
+            Sepolia |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Chiado |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => true,
+
+            // RSKIP-152 (Iris), without the rest of Istanbul
+            Rsk => true,
+
+            // Shut down before Istanbul
+            Morden => false,
+        }
+    }
+
+    /// Returns whether the chain has activated [EIP-3198](https://eips.ethereum.org/EIPS/eip-3198)
+    /// (as part of London), which adds the `BASEFEE` opcode.
+    ///
+    /// Chains for which activation is unknown default to `false`.
+    pub const fn eip3198_active(&self) -> bool {
+        use Chain::*;
+
+        match self {
+            Mainnet | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Optimism |
+            OptimismGoerli | Arbitrum | ArbitrumGoerli | ArbitrumNova | Cronos |
+            CronosTestnet | XDai | Chiado | Polygon | PolygonMumbai | Fantom | FantomTestnet |
+            Moonbeam | MoonbeamDev | Moonriver | Moonbase | Dev | AnvilHardhat | Evmos |
+            EvmosTestnet | Avalanche | AvalancheFuji | Celo | CeloAlfajores | CeloBaklava => true,
+
+            // Hertz (BEP-221), where `BASEFEE` is always zero
+            BinanceSmartChain | BinanceSmartChainTestnet => true,
+
+            // Shut down before London
+            Morden | OptimismKovan | ArbitrumTestnet => false,
+
+            // No EIP-1559 fee market
+            Rsk => false,
+
+            // Unknown
+            Poa | Sokol | Oasis | Emerald | EmeraldTestnet | Aurora | AuroraTestnet => false,
+        }
+    }
+
+    /// Returns which fork-dependent opcodes the chain's EVM supports, e.g. to pick the EVM version
+    /// to compile contracts for.
+    pub fn supported_opcodes(&self) -> OpcodeSupport {
+        let cancun = self.supports_cancun();
+        OpcodeSupport {
+            push0: self.supports_push0(),
+            basefee: self.eip3198_active(),
+            chainid: self.eip1344_active(),
+            mcopy: cancun,
+            transient_storage: cancun,
+            eip6780_selfdestruct: cancun,
+        }
+    }
+
+    /// Returns the SPDX license identifiers the chain's explorer accepts when verifying a
+    /// contract's source code, so that they can be validated before submitting.
+    ///
+    /// Blockscout accepts the same licenses as Etherscan. Returns an empty slice if the chain has
+    /// no explorer.
+    pub const fn supported_verification_licenses(&self) -> &'static [&'static str] {
+        use Chain::*;
+
+        match self {
+            // Etherscan
+            Mainnet |
+            Ropsten |
+            Kovan |
+            Rinkeby |
+            Goerli |
+            Sepolia |
+            Polygon |
+            PolygonMumbai |
+            Avalanche |
+            AvalancheFuji |
+            Optimism |
+            OptimismGoerli |
+            OptimismKovan |
+            Fantom |
+            FantomTestnet |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Moonbeam |
+            Moonbase |
+            Moonriver |
+            Aurora |
+            AuroraTestnet => ETHERSCAN_VERIFICATION_LICENSES,
+
+            // Blockscout
+            ArbitrumGoerli | XDai | Chiado | Sokol | Poa | Rsk | Oasis | Emerald |
+            EmeraldTestnet | Evmos | EvmosTestnet | Celo | CeloAlfajores | CeloBaklava => {
+                ETHERSCAN_VERIFICATION_LICENSES
+            }
+
+            AnvilHardhat | Dev | Morden | MoonbeamDev => &[],
+        }
+    }
+
+    /// Returns the family of related chains this chain belongs to.
+    pub const fn family(&self) -> ChainFamily {
+        use Chain::*;
+
+        match self {
+            Mainnet | Morden | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Dev |
+            AnvilHardhat => ChainFamily::Ethereum,
+            Optimism | OptimismKovan | OptimismGoerli => ChainFamily::Optimism,
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumNova => ChainFamily::Arbitrum,
+            Polygon | PolygonMumbai => ChainFamily::Polygon,
+            BinanceSmartChain | BinanceSmartChainTestnet => ChainFamily::BinanceSmartChain,
+            Avalanche | AvalancheFuji => ChainFamily::Avalanche,
+            Fantom | FantomTestnet => ChainFamily::Fantom,
+            XDai | Chiado => ChainFamily::Gnosis,
+            Celo | CeloAlfajores | CeloBaklava => ChainFamily::Celo,
+            Moonbeam | MoonbeamDev | Moonriver | Moonbase => ChainFamily::Moonbeam,
+            Cronos | CronosTestnet => ChainFamily::Cronos,
+            Evmos | EvmosTestnet => ChainFamily::Evmos,
+            Oasis | Emerald | EmeraldTestnet => ChainFamily::Oasis,
+            Aurora | AuroraTestnet => ChainFamily::Aurora,
+            Rsk | Poa | Sokol => ChainFamily::Other,
+        }
+    }
+
+    /// Returns the hash of the chain's genesis block, which identifies the network in
+    /// peer-discovery and light-client protocols, or `None` if it is not known.
+    pub const fn genesis_hash(&self) -> Option<H256> {
+        use Chain::*;
+
+        let hash = match self {
+            Mainnet => MAINNET_GENESIS_HASH,
+            Ropsten => ROPSTEN_GENESIS_HASH,
+            Rinkeby => RINKEBY_GENESIS_HASH,
+            Goerli => GOERLI_GENESIS_HASH,
+            Kovan => KOVAN_GENESIS_HASH,
+            Sepolia => SEPOLIA_GENESIS_HASH,
+
+            Morden |
+            Optimism |
+            OptimismKovan |
+            OptimismGoerli |
+            Arbitrum |
+            ArbitrumTestnet |
+            ArbitrumGoerli |
+            ArbitrumNova |
+            Cronos |
+            CronosTestnet |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            XDai |
+            Chiado |
+            Polygon |
+            PolygonMumbai |
+            Fantom |
+            FantomTestnet |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Dev |
+            AnvilHardhat |
+            Evmos |
+            EvmosTestnet |
+            Oasis |
+            Emerald |
+            EmeraldTestnet |
+            Avalanche |
+            AvalancheFuji |
+            Celo |
+            CeloAlfajores |
+            CeloBaklava |
+            Aurora |
+            AuroraTestnet => return None,
+        };
+
+        Some(hash)
+    }
+
+    /// Returns the chain whose genesis block has the given hash, see
+    /// [`genesis_hash`](Self::genesis_hash).
+    pub fn from_genesis_hash(hash: H256) -> Option<Self> {
+        use strum::IntoEnumIterator;
+
+        Self::iter().find(|chain| chain.genesis_hash() == Some(hash))
+    }
+
+    /// Returns the number of confirmations after which a block is finalized by the chain's
+    /// consensus, or `None` if the chain has no finality gadget of its own or it is not known.
+    ///
+    /// Rollups return `None`, as their finality is inherited from the L1 they settle on.
+    pub const fn finalized_confirmation_blocks(&self) -> Option<u64> {
+        use Chain::*;
+
+        let blocks = match self {
+            // Casper FFG finalizes a checkpoint after two epochs of 32 slots
+            Mainnet | Goerli | Sepolia => 64,
+            // Gnosis' beacon chain has epochs of 16 slots
+            XDai | Chiado => 32,
+
+            // Snowman, Lachesis, Tendermint, IBFT
+            Avalanche | AvalancheFuji | Fantom | FantomTestnet | Cronos | CronosTestnet |
+            Evmos | EvmosTestnet | Oasis | Emerald | EmeraldTestnet | Celo | CeloAlfajores |
+            CeloBaklava => 1,
+
+            // single node
+            Dev | AnvilHardhat => 1,
+
+            // Finality is inherited from L1
+            Optimism | OptimismKovan | OptimismGoerli | Arbitrum | ArbitrumTestnet |
+            ArbitrumGoerli | ArbitrumNova => return None,
+
+            // Probabilistic finality, or unknown
+            Morden |
+            Ropsten |
+            Rinkeby |
+            Kovan |
+            Rsk |
+            BinanceSmartChain |
+            BinanceSmartChainTestnet |
+            Poa |
+            Sokol |
+            Polygon |
+            PolygonMumbai |
+            Moonbeam |
+            MoonbeamDev |
+            Moonriver |
+            Moonbase |
+            Aurora |
+            AuroraTestnet => return None,
+        };
+
+        Some(blocks)
+    }
+}
+
+/// How to bundle multiple read calls, as returned by [`Chain::read_batching_strategy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReadBatching {
+    /// Send the calls in a single JSON-RPC batch request.
+    JsonRpcBatch,
+    /// Aggregate the calls through the Multicall3 contract at the given address.
+    Multicall3(Address),
+    /// Send the calls one by one.
+    Sequential,
+}
+
+/// What secures the funds held on a chain, as returned by [`Chain::security_model`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SecurityModel {
+    /// Ethereum itself, or one of its testnets.
+    EthereumL1,
+    /// A rollup whose state is secured by Ethereum through fraud proofs.
+    OptimisticRollup,
+    /// A rollup whose state is secured by Ethereum through validity proofs.
+    ///
+    /// No [`Chain`] is a zk-rollup yet, this is reserved for when one is added.
+    ZkRollup,
+    /// A chain secured by its own validators rather than by Ethereum, whether it is bridged to a
+    /// parent chain or standalone.
+    Sidechain,
+    /// A chain run by a fixed set of authorities.
+    ///
+    /// Local development nodes are included, as their only authority is whoever runs them.
+    Consortium,
+}
+
+/// How gas is accounted for on a chain, as returned by [`Chain::gas_semantics`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GasSemantics {
+    /// The transaction's gas limit bounds its execution, and the fee is `gas_used * gas_price`.
+    Standard,
+    /// The gas limit also covers the L1 calldata cost, which is converted into L2 gas at the
+    /// current L1 base fee, so estimates can vary from block to block.
+    ArbitrumL2,
+    /// The gas limit only bounds L2 execution, and an L1 data fee is charged on top of the L2 fee
+    /// outside of the gas limit.
+    OptimismL2,
+}
+
+/// How an RPC endpoint limits the size of `eth_getLogs` queries, as returned by
+/// [`Chain::logs_pagination`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LogsPagination {
+    /// Queries may span at most this many blocks.
+    BlockRange(u64),
+    /// Queries may return at most this many logs, the rest must be fetched with a narrower range.
+    ResultCount(u32),
+    /// The limits are unknown.
+    Unknown,
+}
+
+/// Which block number a chain's `block.number` reports, as returned by
+/// [`Chain::block_number_semantics`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BlockNumberSemantics {
+    /// `block.number` is the number of the chain's own block.
+    Native,
+    /// `block.number` is an approximation of the L1 block number at the time the L2 block was
+    /// produced. The L2 block number must be read from `ArbSys.arbBlockNumber()` instead.
+    ArbitrumL1Reflected,
+}
+
+/// Nonstandard transaction encodings, as returned by [`Chain::tx_encoding_quirks`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TxEncodingQuirks {
+    /// Transactions are encoded as on Ethereum.
+    Standard,
+    /// Legacy transactions include the `feeCurrency`, `gatewayFeeRecipient` and `gatewayFee`
+    /// fields, which are supported by enabling the `celo` feature.
+    Celo,
+}
+
+/// A group of related chains, like a mainnet with its testnets, as returned by [`Chain::family`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChainFamily {
+    /// Ethereum, its testnets, and local development chains.
+    Ethereum,
+    /// Optimism and other OP-stack chains.
+    Optimism,
+    /// Arbitrum One, Nova, and their testnets.
+    Arbitrum,
+    /// Polygon PoS and its testnets.
+    Polygon,
+    /// BNB Smart Chain and its testnets.
+    BinanceSmartChain,
+    /// Avalanche C-Chain and its testnets.
+    Avalanche,
+    /// Fantom Opera and its testnets.
+    Fantom,
+    /// Gnosis Chain and its testnets.
+    Gnosis,
+    /// Celo and its testnets.
+    Celo,
+    /// Moonbeam, Moonriver, and their testnets.
+    Moonbeam,
+    /// Cronos and its testnets.
+    Cronos,
+    /// Evmos and its testnets.
+    Evmos,
+    /// Oasis and its EVM-compatible ParaTimes.
+    Oasis,
+    /// Aurora and its testnets.
+    Aurora,
+    /// A chain that doesn't belong to any of the other families.
+    Other,
+}
+
+/// The fork-dependent opcodes a chain supports, as returned by [`Chain::supported_opcodes`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OpcodeSupport {
+    /// `PUSH0`, added in Shanghai.
+    pub push0: bool,
+    /// `BASEFEE`, added in London.
+    pub basefee: bool,
+    /// `CHAINID`, added in Istanbul.
+    pub chainid: bool,
+    /// `MCOPY`, added in Cancun.
+    pub mcopy: bool,
+    /// `TSTORE` and `TLOAD`, added in Cancun.
+    pub transient_storage: bool,
+    /// Whether `SELFDESTRUCT` only deletes contracts created in the same transaction, as changed
+    /// in Cancun by [EIP-6780](https://eips.ethereum.org/EIPS/eip-6780).
+    pub eip6780_selfdestruct: bool,
+}
+
+/// The URLs of a chain's Etherscan-like explorer, as returned by [`Chain::iter_with_explorer`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EtherscanUrls {
+    /// The API URL, like `https://api.etherscan.io/api`.
+    pub api: Url,
+    /// The base URL, like `https://etherscan.io`.
+    pub base: Url,
+}
+
+/// Parses a chain name like `FromStr`, but case-insensitively and also accepting `snake_case`
+/// variant names, like `binance_smart_chain`.
+fn parse_chain_name(name: &str) -> Option<Chain> {
+    use strum::IntoEnumIterator;
+
+    let name = name.to_ascii_lowercase();
+    if let Ok(chain) = name.parse() {
+        return Some(chain)
+    }
+    let name = name.replace('_', "");
+    Chain::iter().find(|chain| format!("{chain:?}").eq_ignore_ascii_case(&name))
+}
+
+/// Parses a chain id, as a decimal or hex string, or else a chain name, see [`parse_chain_name`].
+fn parse_chain_id_or_name<E: de::Error>(s: &str) -> Result<ChainId, E> {
+    let id = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => s.parse::<u64>().ok(),
+    };
+    if let Some(id) = id {
+        return Ok(id.into())
+    }
+    parse_chain_name(s)
+        .map(ChainId::Named)
+        .ok_or_else(|| de::Error::unknown_variant(s, Chain::VARIANTS))
+}
+
+/// Formats a fee amount in the smallest unit as a decimal amount with `decimals` decimals, see
+/// [`Chain::format_fee`].
+fn format_fee_with_decimals(fee: U256, decimals: u8) -> String {
+    crate::utils::format_units(fee, u32::from(decimals))
+        .expect("fee decimals are below the U256 overflow limit")
+}
+
+/// Returns the Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == *cb { prev } else { 1 + cmp::min(prev, cmp::min(row[j], cur)) };
+            prev = cur;
+        }
+    }
+    row[b.len()]
+}
+
+/// A chain's metadata, as returned by [`Chain::network_config`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkConfig {
+    /// The chain.
+    pub chain: Chain,
+    /// The EIP-155 chain id.
+    pub chain_id: u64,
+    /// The API URL of the chain's Etherscan-like explorer, if any.
+    pub explorer_api_url: Option<&'static str>,
+    /// The base URL of the chain's Etherscan-like explorer, if any.
+    pub explorer_url: Option<&'static str>,
+    /// The average blocktime in milliseconds, if known.
+    pub average_blocktime_ms: Option<u64>,
+    /// The ticker of the chain's native currency.
+    pub native_currency_symbol: &'static str,
+    /// Whether the chain only supports legacy transactions.
+    pub is_legacy: bool,
+    /// The number of confirmations after which a transaction is considered safe.
+    pub safe_confirmations: u64,
+    /// The number of confirmations after which a block is finalized, if the chain has a finality
+    /// gadget of its own.
+    pub finalized_confirmations: Option<u64>,
+}
+
+/// An EIP-155 chain id, which is either a known [`Chain`] or an arbitrary id.
+///
+/// Unlike [`Chain`], any `u64` can be converted into a `ChainId`, so custom and newly launched
+/// networks can be represented as well.
+///
+/// # Examples
+///
+/// ```
+/// use ethers_core::types::{Chain, ChainId};
+///
+/// assert_eq!(ChainId::from(1).as_named(), Some(Chain::Mainnet));
+/// assert_eq!(ChainId::from(99999).as_named(), None);
+/// assert_eq!(ChainId::from(99999).id(), 99999);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChainId {
+    /// A known chain.
+    Named(Chain),
+    /// A chain which is not known to this crate.
+    Id(u64),
+}
+
+// === impl ChainId ===
+
+impl ChainId {
+    /// Returns the numeric chain id.
+    pub const fn id(&self) -> u64 {
+        match self {
+            ChainId::Named(chain) => *chain as u64,
+            ChainId::Id(id) => *id,
+        }
+    }
+
+    /// Returns the known [`Chain`], if any.
+    pub const fn as_named(&self) -> Option<Chain> {
+        match self {
+            ChainId::Named(chain) => Some(*chain),
+            ChainId::Id(_) => None,
+        }
+    }
+
+    /// Returns whether this is a known [`Chain`].
+    pub const fn is_known(&self) -> bool {
+        matches!(self, ChainId::Named(_))
+    }
+}
+
+impl From<u64> for ChainId {
+    fn from(id: u64) -> Self {
+        match Chain::try_from(id) {
+            Ok(chain) => ChainId::Named(chain),
+            Err(_) => ChainId::Id(id),
+        }
+    }
+}
+
+impl From<Chain> for ChainId {
+    fn from(chain: Chain) -> Self {
+        ChainId::Named(chain)
+    }
+}
+
+impl From<ChainId> for u64 {
+    fn from(id: ChainId) -> Self {
+        id.id()
+    }
+}
+
+impl fmt::Display for ChainId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChainId::Named(chain) => chain.fmt(f),
+            ChainId::Id(id) => id.fmt(f),
+        }
+    }
+}
+
+impl Serialize for ChainId {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ChainId::Named(chain) => chain.serialize(s),
+            ChainId::Id(id) => s.serialize_u64(*id),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ChainIdVisitor;
+
+        impl<'de> Visitor<'de> for ChainIdVisitor {
+            type Value = ChainId;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a chain name or id")
+            }
+
+            fn visit_u64<E: de::Error>(self, id: u64) -> Result<Self::Value, E> {
+                Ok(id.into())
+            }
+
+            fn visit_i64<E: de::Error>(self, id: i64) -> Result<Self::Value, E> {
+                let id = u64::try_from(id)
+                    .map_err(|_| de::Error::custom(format!("invalid chain id: {id}")))?;
+                self.visit_u64(id)
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                parse_chain_id_or_name(s)
+            }
+        }
+
+        deserializer.deserialize_any(ChainIdVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_default_chain() {
+        assert_eq!(serde_json::to_string(&Chain::default()).unwrap(), "\"mainnet\"");
+    }
+
+    #[test]
+    fn test_enum_iter() {
+        assert_eq!(Chain::COUNT, Chain::iter().size_hint().0);
+    }
+
+    #[test]
+    fn test_confirmations_label() {
+        let chain = Chain::Mainnet;
+        assert_eq!(chain.confirmations_label(3), "3/12 confirmations");
+        assert_eq!(chain.confirmations_label_with_eta(3), "3/12 confirmations (~1m 57s remaining)");
+        assert_eq!(chain.confirmations_label_with_eta(12), "12/12 confirmations");
+    }
+
+    #[test]
+    fn test_network_config() {
+        let config = Chain::Mainnet.network_config();
+        assert_eq!(
+            serde_json::to_value(config).unwrap(),
+            serde_json::json!({
+                "chain": "mainnet",
+                "chainId": 1,
+                "explorerApiUrl": "https://api.etherscan.io/api",
+                "explorerUrl": "https://etherscan.io",
+                "averageBlocktimeMs": 13000,
+                "nativeCurrencySymbol": "ETH",
+                "isLegacy": false,
+                "safeConfirmations": 12,
+                "finalizedConfirmations": 64,
+            })
+        );
+
+        let config = Chain::Dev.network_config();
+        assert_eq!(config.explorer_api_url, None);
+        assert_eq!(config.explorer_url, None);
+
+        let config = Chain::Optimism.network_config();
+        assert_eq!(config.finalized_confirmations, None);
+    }
+
+    #[test]
+    fn test_suggest() {
+        assert_eq!(Chain::suggest("optimsm").first(), Some(&Chain::Optimism));
+        assert_eq!(Chain::suggest("Polygn").first(), Some(&Chain::Polygon));
+        // aliases are considered as well
+        assert_eq!(Chain::suggest("gnosiss").first(), Some(&Chain::XDai));
+        assert!(Chain::suggest("definitely-not-a-chain").is_empty());
+
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("abc", "abc"), 0);
+    }
+
+    #[test]
+    fn test_default_reward_percentiles() {
+        assert_eq!(Chain::Mainnet.default_reward_percentiles(), &[10.0, 50.0, 90.0]);
+        for chain in Chain::iter() {
+            assert_eq!(chain.is_legacy(), chain.default_reward_percentiles().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_create2_address() {
+        // https://github.com/ethereum/EIPs/blob/master/EIPS/eip-1014.md#examples
+        let deployer = "0xdeadbeef00000000000000000000000000000000".parse().unwrap();
+        let init_code_hash = H256(crate::utils::keccak256([0x00]));
+        let expected: Address = "0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3".parse().unwrap();
+        for chain in [Chain::Mainnet, Chain::Polygon, Chain::Optimism] {
+            assert_eq!(chain.create2_address(deployer, H256::zero(), init_code_hash), expected);
+        }
+    }
+
+    #[test]
+    fn test_is_sidechain() {
+        assert!(Chain::Polygon.is_sidechain());
+        assert!(Chain::XDai.is_sidechain());
+        assert!(Chain::BinanceSmartChain.is_sidechain());
+        assert!(!Chain::Optimism.is_sidechain());
+        assert!(!Chain::Arbitrum.is_sidechain());
+        assert!(!Chain::Mainnet.is_sidechain());
+        assert!(Chain::Avalanche.is_sidechain());
+        assert!(!Chain::Poa.is_sidechain());
+    }
+
+    #[test]
+    fn test_security_model() {
+        for chain in Chain::iter() {
+            let model = chain.security_model();
+            if chain.is_permissioned() {
+                assert_eq!(model, SecurityModel::Consortium, "{chain}");
+            }
+            assert_eq!(chain.is_sidechain(), model == SecurityModel::Sidechain, "{chain}");
+        }
+        assert_eq!(Chain::Mainnet.security_model(), SecurityModel::EthereumL1);
+        assert_eq!(Chain::Optimism.security_model(), SecurityModel::OptimisticRollup);
+        assert_eq!(Chain::Avalanche.security_model(), SecurityModel::Sidechain);
+        assert_eq!(Chain::AnvilHardhat.security_model(), SecurityModel::Consortium);
+    }
+
+    #[test]
+    fn test_is_shutdown() {
+        assert!(Chain::Ropsten.is_shutdown());
+        assert!(Chain::Goerli.is_deprecated());
+        assert!(!Chain::Goerli.is_shutdown());
+        assert!(!Chain::Mainnet.is_deprecated());
+        for chain in Chain::iter().filter(Chain::is_shutdown) {
+            assert!(chain.is_deprecated(), "{chain}");
+        }
+    }
+
+    #[test]
+    fn test_rpc_url_env_var() {
+        assert_eq!(Chain::Polygon.rpc_url_env_var(), "POLYGON_RPC_URL");
+        assert_eq!(Chain::CeloBaklava.rpc_url_env_var(), "CELO_BAKLAVA_RPC_URL");
+        assert_eq!(Chain::BinanceSmartChainTestnet.rpc_url_env_var(), "BSC_TESTNET_RPC_URL");
+    }
+
+    #[test]
+    fn test_rpc_url_from_vars() {
+        use std::collections::HashMap;
+
+        let lookup = |vars: &[(&str, &str)]| {
+            let vars: HashMap<_, _> = vars.iter().copied().collect();
+            Chain::Polygon.rpc_url_from_vars(|var| vars.get(var).map(|url| url.to_string()))
+        };
+
+        // the chain-specific variable takes precedence, then `ETH_RPC_URL`, then `RPC_URL`
+        let all = [("POLYGON_RPC_URL", "polygon"), ("ETH_RPC_URL", "eth"), ("RPC_URL", "rpc")];
+        assert_eq!(lookup(&all).as_deref(), Some("polygon"));
+        assert_eq!(lookup(&all[1..]).as_deref(), Some("eth"));
+        assert_eq!(lookup(&all[2..]).as_deref(), Some("rpc"));
+        assert_eq!(lookup(&[]), None);
+
+        // empty values are skipped
+        assert_eq!(lookup(&[("POLYGON_RPC_URL", ""), ("RPC_URL", "rpc")]).as_deref(), Some("rpc"));
+        assert_eq!(lookup(&[("ETH_RPC_URL", "")]), None);
+    }
+
+    #[test]
+    fn test_from_native_ticker() {
+        assert_eq!(Chain::from_native_ticker("MATIC"), Some(Chain::Polygon));
+        assert_eq!(Chain::from_native_ticker("BNB"), Some(Chain::BinanceSmartChain));
+        assert_eq!(Chain::from_native_ticker("eth"), Some(Chain::Mainnet));
+        assert_eq!(Chain::from_native_ticker("NOPE"), None);
+    }
+
+    #[test]
+    fn test_deterministic_finality() {
+        assert!(Chain::Avalanche.has_deterministic_finality());
+        assert!(!Chain::Mainnet.has_deterministic_finality());
+        for chain in Chain::iter().filter(Chain::has_deterministic_finality) {
+            assert_eq!(chain.safe_confirmation_blocks(), 1, "{chain}");
+        }
+    }
+
+    #[test]
+    fn test_eip1559_unavailable_reason() {
+        for chain in Chain::iter() {
+            assert_eq!(chain.is_legacy(), chain.eip1559_unavailable_reason().is_some(), "{chain}");
+        }
+    }
+
+    #[test]
+    fn test_messenger_addresses() {
+        assert_eq!(
+            Chain::Optimism.l1_messenger_address(),
+            Some("0x25ace71c97B33Cc4729CF772ae268934F7ab5fA1".parse().unwrap())
+        );
+        assert_eq!(
+            Chain::Arbitrum.l1_messenger_address(),
+            Some("0x4Dbd4fc535Ac27206064B68FfCf827b0A60BAB3f".parse().unwrap())
+        );
+        assert_eq!(
+            Chain::ArbitrumNova.l1_messenger_address(),
+            Some("0xc4448b71118c9071Bcb9734A0EAc55D18A153949".parse().unwrap())
+        );
+        assert_eq!(
+            Chain::Optimism.l2_messenger_address(),
+            Some("0x4200000000000000000000000000000000000007".parse().unwrap())
+        );
+        assert_eq!(Chain::Mainnet.l1_messenger_address(), None);
+        assert_eq!(Chain::Polygon.l2_messenger_address(), None);
+    }
+
+    #[test]
+    fn test_is_testnet_of() {
+        assert!(Chain::OptimismGoerli.is_testnet_of(Chain::Optimism));
+        assert!(Chain::Sepolia.is_testnet_of(Chain::Mainnet));
+        assert!(Chain::CeloBaklava.is_testnet_of(Chain::Celo));
+
+        assert!(!Chain::OptimismGoerli.is_testnet_of(Chain::Mainnet));
+        assert!(!Chain::Mainnet.is_testnet_of(Chain::Mainnet));
+        assert!(!Chain::ArbitrumNova.is_testnet_of(Chain::Arbitrum));
+    }
+
+    #[test]
+    fn test_etherscan_urls_order() {
+        for chain in Chain::iter() {
+            if let Some((api, base)) = chain.explorer_urls() {
+                assert!(api.contains("/api"), "{chain}: {api}");
+                assert!(!base.contains("/api"), "{chain}: {base}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_testnet() {
+        assert!(Chain::Sepolia.is_testnet());
+        assert!(Chain::OptimismGoerli.is_testnet());
+        assert!(Chain::AnvilHardhat.is_testnet());
+
+        assert!(!Chain::Mainnet.is_testnet());
+        assert!(!Chain::Polygon.is_testnet());
+        assert!(!Chain::ArbitrumNova.is_testnet());
+
+        for chain in Chain::iter() {
+            for other in Chain::iter() {
+                if chain.is_testnet_of(other) {
+                    assert!(chain.is_testnet(), "{chain}");
+                    assert!(!other.is_testnet(), "{other}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_native_currency_symbol() {
+        assert_eq!(Chain::Mainnet.native_currency_symbol(), "ETH");
+        assert_eq!(Chain::Arbitrum.native_currency_symbol(), "ETH");
+        assert_eq!(Chain::Polygon.native_currency_symbol(), "MATIC");
+        assert_eq!(Chain::BinanceSmartChain.native_currency_symbol(), "BNB");
+        assert_eq!(Chain::XDai.native_currency_symbol(), "xDAI");
+
+        // every production chain's ticker is recognized
+        for chain in Chain::iter().filter(|c| !c.is_testnet()) {
+            assert!(Chain::from_native_ticker(chain.native_currency_symbol()).is_some(), "{chain}");
+        }
+    }
+
+    #[test]
+    fn test_is_reserved_chain_id() {
+        assert!(Chain::is_reserved_chain_id(0));
+        assert!(Chain::is_reserved_chain_id(1337));
+        assert!(Chain::is_reserved_chain_id(31337));
+        assert!(Chain::is_reserved_chain_id(u64::MAX));
+
+        assert!(!Chain::is_reserved_chain_id(1));
+        assert!(!Chain::is_reserved_chain_id(137));
+    }
+
+    #[test]
+    fn test_chain_id() {
+        let id = ChainId::from(99999);
+        assert_eq!(id, ChainId::Id(99999));
+        assert_eq!(u64::from(id), 99999);
+        assert_eq!(serde_json::to_string(&id).unwrap(), "99999");
+        assert_eq!(serde_json::from_str::<ChainId>("99999").unwrap(), id);
+
+        let id = ChainId::from(56);
+        assert_eq!(id.as_named(), Some(Chain::BinanceSmartChain));
+        assert_eq!(id.to_string(), "bsc");
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"bsc\"");
+        assert_eq!(serde_json::from_str::<ChainId>("\"bsc\"").unwrap(), id);
+        assert_eq!(serde_json::from_str::<ChainId>("56").unwrap(), id);
+
+        assert!(serde_json::from_str::<ChainId>("\"not-a-chain\"").is_err());
+
+        // ids as strings, like `Chain`
+        let mainnet = ChainId::Named(Chain::Mainnet);
+        assert_eq!(serde_json::from_str::<ChainId>("\"0x1\"").unwrap(), mainnet);
+        assert_eq!(serde_json::from_str::<ChainId>("\"1\"").unwrap(), mainnet);
+        assert_eq!(serde_json::from_str::<ChainId>("\"0x1869f\"").unwrap(), ChainId::Id(99999));
+        assert!(serde_json::from_str::<Chain>("\"0x1869f\"").is_err());
+    }
+
+    #[test]
+    fn test_gas_oracle_refresh_interval() {
+        assert!(
+            Chain::Arbitrum.gas_oracle_refresh_interval() <
+                Chain::Mainnet.gas_oracle_refresh_interval()
+        );
+        assert_eq!(Chain::AnvilHardhat.gas_oracle_refresh_interval(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_wrapped_native_token() {
+        assert_eq!(
+            Chain::Mainnet.wrapped_native_token(),
+            Some("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".parse().unwrap())
+        );
+        assert_eq!(
+            Chain::Polygon.wrapped_native_token(),
+            Some("0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270".parse().unwrap())
+        );
+        assert_eq!(Chain::Dev.wrapped_native_token(), None);
+
+        for chain in Chain::iter() {
+            if chain.wrapped_native_token().is_some() {
+                assert!(chain.wrapped_native_symbol().is_some(), "{chain}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_multicall3_address() {
+        let multicall3 = "0xcA11bde05977b3631167028862bE2a173976CA11".parse().unwrap();
+        assert_eq!(Chain::Mainnet.multicall3_address(), Some(multicall3));
+        assert_eq!(Chain::Polygon.multicall3_address(), Some(multicall3));
+        assert_eq!(Chain::Morden.multicall3_address(), None);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        for (s, chain) in [
+            ("\"mainnet\"", Chain::Mainnet),
+            ("\"Mainnet\"", Chain::Mainnet),
+            ("\"bsc\"", Chain::BinanceSmartChain),
+            ("\"xdai\"", Chain::XDai),
+            ("\"gnosis-chain\"", Chain::XDai),
+            ("\"polygon-mumbai\"", Chain::PolygonMumbai),
+            // previously used `snake_case` names
+            ("\"binance_smart_chain\"", Chain::BinanceSmartChain),
+            ("\"x_dai\"", Chain::XDai),
+            ("137", Chain::Polygon),
+            ("\"137\"", Chain::Polygon),
+        ] {
+            assert_eq!(serde_json::from_str::<Chain>(s).unwrap(), chain, "{s}");
+        }
+
+        for chain in Chain::iter() {
+            let s = serde_json::to_string(&chain).unwrap();
+            assert_eq!(serde_json::from_str::<Chain>(&s).unwrap(), chain);
+        }
+
+        assert!(serde_json::from_str::<Chain>("\"not-a-chain\"").is_err());
+    }
+
+    #[test]
+    fn test_explorer_search_url() {
+        assert_eq!(
+            Chain::Moonbeam.explorer_search_url("0x0000 &=?").unwrap(),
+            "https://moonbeam.moonscan.io/search?q=0x0000+%26%3D%3F"
+        );
+        assert_eq!(Chain::Dev.explorer_search_url("vitalik.eth"), None);
+    }
+
+    #[test]
+    fn test_deserialize_chain_id() {
+        assert_eq!(serde_json::from_str::<Chain>("1").unwrap(), Chain::Mainnet);
+        assert_eq!(serde_json::from_str::<Chain>("\"0x1\"").unwrap(), Chain::Mainnet);
+        assert_eq!(serde_json::from_str::<Chain>("\"0x89\"").unwrap(), Chain::Polygon);
+
+        let err = serde_json::from_str::<Chain>("999999").unwrap_err();
+        assert!(err.to_string().contains("unknown chain id: 999999"), "{err}");
+        let err = serde_json::from_str::<Chain>("\"0xf423f\"").unwrap_err();
+        assert!(err.to_string().contains("unknown chain id: 999999"), "{err}");
+    }
+
+    #[test]
+    fn test_deserialize_i64() {
+        use serde::de::{value::I64Deserializer, IntoDeserializer};
+
+        let de: I64Deserializer<de::value::Error> = 137i64.into_deserializer();
+        assert_eq!(Chain::deserialize(de).unwrap(), Chain::Polygon);
+        let de: I64Deserializer<de::value::Error> = 137i64.into_deserializer();
+        assert_eq!(ChainId::deserialize(de).unwrap(), ChainId::Named(Chain::Polygon));
+        let de: I64Deserializer<de::value::Error> = 99999i64.into_deserializer();
+        assert_eq!(ChainId::deserialize(de).unwrap(), ChainId::Id(99999));
+
+        let de: I64Deserializer<de::value::Error> = (-1i64).into_deserializer();
+        assert!(Chain::deserialize(de).unwrap_err().to_string().contains("invalid chain id: -1"));
+        let de: I64Deserializer<de::value::Error> = (-1i64).into_deserializer();
+        assert!(ChainId::deserialize(de).is_err());
+    }
+
+    #[test]
+    fn test_etherscan_api_key_name() {
+        assert_eq!(Chain::Mainnet.etherscan_api_key_name(), Some("ETHERSCAN_API_KEY"));
+        assert_eq!(Chain::Sepolia.etherscan_api_key_name(), Some("ETHERSCAN_API_KEY"));
+        assert_eq!(Chain::Polygon.etherscan_api_key_name(), Some("POLYGONSCAN_API_KEY"));
+        assert_eq!(Chain::XDai.etherscan_api_key_name(), None);
+
+        for chain in Chain::iter() {
+            if chain.etherscan_api_key_name().is_some() {
+                assert!(chain.explorer_urls().is_some(), "{chain}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_etherscan_url() {
+        assert_eq!(
+            Chain::Mainnet.etherscan_api_url().unwrap().as_str(),
+            "https://api.etherscan.io/api"
+        );
+        assert_eq!(Chain::Mainnet.etherscan_base_url().unwrap().as_str(), "https://etherscan.io/");
+
+        for chain in Chain::iter() {
+            let (api, base) = match (chain.etherscan_api_url(), chain.etherscan_base_url()) {
+                (Some(api), Some(base)) => (api, base),
+                (None, None) => continue,
+                _ => panic!("{chain}: only one of the explorer URLs is set"),
+            };
+            for url in [api, base] {
+                assert_eq!(url.scheme(), "https", "{chain}: {url}");
+                assert!(url.host_str().map_or(false, |host| !host.is_empty()), "{chain}: {url}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_usdc_address() {
+        assert_eq!(
+            Chain::Mainnet.usdc_address(),
+            Some("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".parse().unwrap())
+        );
+        assert_eq!(
+            Chain::Arbitrum.usdc_address(),
+            Some("0xaf88d065e77c8cC2239327C5EDb3A432268e5831".parse().unwrap())
+        );
+        assert_eq!(Chain::BinanceSmartChain.usdc_address(), None);
+    }
+
+    #[test]
+    fn test_explorer_page_urls() {
+        let hash: H256 =
+            "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060".parse().unwrap();
+        assert_eq!(
+            Chain::Mainnet.explorer_tx_url(hash).unwrap().as_str(),
+            "https://etherscan.io/tx/0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060"
+        );
+
+        let address: Address = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".parse().unwrap();
+        assert_eq!(
+            Chain::Mainnet.explorer_token_url(address).unwrap().as_str(),
+            "https://etherscan.io/token/0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"
+        );
+        // the base URL's path and trailing slash are preserved
+        assert_eq!(
+            Chain::Celo.explorer_address_url(address).unwrap().as_str(),
+            "https://explorer.celo.org/mainnet/address/0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"
+        );
+        assert_eq!(
+            Chain::Moonbeam.explorer_address_url(address).unwrap().as_str(),
+            "https://moonbeam.moonscan.io/address/0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"
+        );
+        assert_eq!(Chain::Dev.explorer_tx_url(hash), None);
+    }
+
+    #[test]
+    fn test_is_rollup() {
+        for chain in [Chain::Optimism, Chain::OptimismGoerli] {
+            assert!(chain.is_rollup());
+            assert!(chain.is_optimistic_rollup());
+            assert!(!chain.is_arbitrum());
+        }
+        for chain in [Chain::Arbitrum, Chain::ArbitrumGoerli, Chain::ArbitrumNova] {
+            assert!(chain.is_rollup());
+            assert!(!chain.is_optimistic_rollup());
+            assert!(chain.is_arbitrum());
+        }
+        for chain in [Chain::Mainnet, Chain::Polygon] {
+            assert!(!chain.is_rollup());
+            assert!(!chain.is_optimistic_rollup());
+            assert!(!chain.is_arbitrum());
+        }
+
+        for chain in Chain::iter() {
+            assert_eq!(chain.is_rollup(), chain.is_optimistic_rollup() || chain.is_arbitrum());
+        }
+    }
+
+    #[test]
+    fn test_is_known() {
+        assert_eq!(Chain::try_known_from(137), Some(Chain::Polygon));
+        assert_eq!(Chain::try_known_from(99999), None);
+
+        assert!(ChainId::from(137).is_known());
+        assert!(!ChainId::from(99999).is_known());
+    }
+
+    #[test]
+    fn test_mainnet_counterpart() {
+        assert_eq!(Chain::Goerli.mainnet_counterpart(), Some(Chain::Mainnet));
+        assert_eq!(Chain::PolygonMumbai.mainnet_counterpart(), Some(Chain::Polygon));
+        assert_eq!(Chain::AvalancheFuji.mainnet_counterpart(), Some(Chain::Avalanche));
+        assert_eq!(Chain::ArbitrumGoerli.mainnet_counterpart(), Some(Chain::Arbitrum));
+        assert_eq!(Chain::Mainnet.mainnet_counterpart(), None);
+        // Nova is a separate production chain, not a testnet
+        assert_eq!(Chain::ArbitrumNova.mainnet_counterpart(), None);
+
+        assert_eq!(Chain::Optimism.testnets(), vec![Chain::OptimismKovan, Chain::OptimismGoerli]);
+        assert_eq!(Chain::Arbitrum.testnets(), vec![Chain::ArbitrumTestnet, Chain::ArbitrumGoerli]);
+        assert!(Chain::Sepolia.testnets().is_empty());
+    }
+
+    #[test]
+    fn test_average_blocktime_secs() {
+        assert_eq!(Chain::Arbitrum.average_blocktime_secs(), Some(1.3));
+        assert_eq!(Chain::Mainnet.average_blocktime_secs(), Some(13.0));
+        assert_eq!(Chain::Sepolia.average_blocktime_secs(), None);
+    }
+
+    #[test]
+    fn test_try_from_signed() {
+        assert_eq!(Chain::try_from(1i64).unwrap(), Chain::Mainnet);
+        assert_eq!(Chain::try_from(137i32).unwrap(), Chain::Polygon);
+        assert_eq!(Chain::try_from(-1i32).unwrap_err().number, 0);
+        assert!(Chain::try_from(i64::MIN).is_err());
+    }
+
+    #[test]
+    fn test_as_num() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Config {
+            #[serde(with = "as_num")]
+            chain: Chain,
+        }
+
+        let config = Config { chain: Chain::Polygon };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"chain":137}"#);
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+        assert!(serde_json::from_str::<Config>(r#"{"chain":999999}"#).is_err());
+        assert!(serde_json::from_str::<Config>(r#"{"chain":"polygon"}"#).is_err());
+    }
+
+    #[test]
+    fn test_ens_registry_address() {
+        assert_eq!(
+            Chain::Mainnet.ens_registry_address(),
+            Some("0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e".parse().unwrap())
+        );
+        assert_eq!(Chain::Polygon.ens_registry_address(), None);
+    }
+
+    #[test]
+    fn test_iter_with_explorer() {
+        let chains = Chain::iter_with_explorer().collect::<Vec<_>>();
+        assert_eq!(chains.len(), Chain::iter().filter(|c| c.explorer_urls().is_some()).count());
+
+        let (chain, urls) = &chains[0];
+        assert_eq!(*chain, Chain::Mainnet);
+        assert_eq!(urls.api.as_str(), "https://api.etherscan.io/api");
+        assert!(chains.iter().all(|(chain, _)| *chain != Chain::Dev));
+    }
+
+    #[test]
+    fn test_iter_production_rollups() {
+        let rollups = Chain::iter_production_rollups().collect::<Vec<_>>();
+        assert!(rollups.contains(&Chain::Optimism));
+        assert!(rollups.contains(&Chain::Arbitrum));
+        assert!(rollups.contains(&Chain::ArbitrumNova));
+        assert!(!rollups.contains(&Chain::OptimismGoerli));
+        assert!(!rollups.contains(&Chain::ArbitrumGoerli));
+        assert!(!rollups.contains(&Chain::Mainnet));
+    }
+
+    #[test]
+    fn test_is_legacy() {
+        assert!(!Chain::XDai.is_legacy());
+        assert!(!Chain::Chiado.is_legacy());
+        assert!(!Chain::Moonbeam.is_legacy());
+        assert!(!Chain::Moonriver.is_legacy());
+        assert!(!Chain::Cronos.is_legacy());
+        assert!(Chain::BinanceSmartChain.is_legacy());
+    }
+
+    #[test]
+    fn test_supports_push0() {
+        assert!(Chain::Mainnet.supports_push0());
+        assert!(Chain::Arbitrum.supports_shanghai());
+        assert!(Chain::Dev.supports_push0());
+        assert!(Chain::AnvilHardhat.supports_push0());
+        assert!(!Chain::Fantom.supports_push0());
+        assert!(!Chain::Ropsten.supports_shanghai());
+
+        // Cancun implies Shanghai
+        for chain in Chain::iter().filter(Chain::supports_beacon_block_root) {
+            assert!(chain.supports_push0(), "{chain}");
+        }
+    }
+
+    #[test]
+    fn test_supports_blob_transactions() {
+        assert!(Chain::Sepolia.supports_blob_transactions());
+        assert!(!Chain::Optimism.supports_blob_transactions());
+        assert!(!Chain::Polygon.supports_blob_transactions());
+        assert!(Chain::BinanceSmartChain.supports_blob_transactions());
+
+        for chain in Chain::iter() {
+            assert_eq!(
+                chain.supports_blob_transactions(),
+                chain.min_blob_base_fee().is_some(),
+                "{chain}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_recommended_confirmations() {
+        assert_eq!(Chain::Dev.recommended_confirmations(), 1);
+        assert_eq!(Chain::AnvilHardhat.recommended_confirmations(), 1);
+        assert!(
+            Chain::Polygon.recommended_confirmations() > Chain::Mainnet.recommended_confirmations()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "clap")]
+    fn test_clap_value_enum() {
+        let cmd = clap::Command::new("test")
+            .arg(clap::Arg::new("chain").long("chain").value_parser(clap::value_parser!(Chain)));
+        for (arg, chain) in [
+            ("mainnet", Chain::Mainnet),
+            ("bsc", Chain::BinanceSmartChain),
+            ("polygon-mumbai", Chain::PolygonMumbai),
+            ("mumbai", Chain::PolygonMumbai),
+            ("gnosis", Chain::XDai),
+        ] {
+            let matches = cmd.clone().try_get_matches_from(["test", "--chain", arg]).unwrap();
+            assert_eq!(matches.get_one::<Chain>("chain"), Some(&chain), "{arg}");
+        }
+        assert!(cmd.try_get_matches_from(["test", "--chain", "not-a-chain"]).is_err());
+    }
+
+    #[test]
+    fn test_estimate_gas_supports_auth_list() {
+        assert!(Chain::Mainnet.estimate_gas_supports_auth_list());
+        assert!(Chain::Sepolia.estimate_gas_supports_auth_list());
+        assert!(!Chain::Optimism.estimate_gas_supports_auth_list());
+        assert!(!Chain::Goerli.estimate_gas_supports_auth_list());
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn test_arbitrary_roundtrip() {
+        use arbitrary::{Arbitrary, Unstructured};
+        use rand::RngCore;
+
+        let mut bytes = [0u8; 1024];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        let mut u = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let chain = Chain::arbitrary(&mut u).unwrap();
+            assert_eq!(Chain::try_from(chain as u64).unwrap(), chain);
+            let json = serde_json::to_string(&chain).unwrap();
+            assert_eq!(serde_json::from_str::<Chain>(&json).unwrap(), chain);
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_chain_strategy_roundtrip(chain in chain_strategy()) {
+            proptest::prop_assert_eq!(Chain::try_from(chain as u64).unwrap(), chain);
+            let json = serde_json::to_string(&chain).unwrap();
+            proptest::prop_assert_eq!(serde_json::from_str::<Chain>(&json).unwrap(), chain);
+        }
+    }
+
+    #[test]
+    fn test_supported_opcodes() {
+        let opcodes = Chain::Mainnet.supported_opcodes();
+        assert!(opcodes.push0 && opcodes.basefee && opcodes.chainid);
+        assert!(opcodes.mcopy && opcodes.transient_storage && opcodes.eip6780_selfdestruct);
+
+        let opcodes = Chain::Arbitrum.supported_opcodes();
+        assert!(opcodes.mcopy && opcodes.transient_storage);
+
+        let opcodes = Chain::Dev.supported_opcodes();
+        assert!(opcodes.mcopy && opcodes.transient_storage);
+
+        // RSK adopted `CHAINID` on its own, without London or Shanghai
+        let opcodes = Chain::Rsk.supported_opcodes();
+        assert!(opcodes.chainid);
+        assert!(!opcodes.basefee && !opcodes.push0 && !opcodes.mcopy);
+
+        let opcodes = Chain::Fantom.supported_opcodes();
+        assert!(!opcodes.push0 && !opcodes.mcopy && !opcodes.transient_storage);
+
+        for chain in Chain::iter() {
+            if chain.supports_blob_transactions() || chain.supports_beacon_block_root() {
+                assert!(chain.supports_cancun(), "{chain}");
+            }
+            // Cancun implies Shanghai
+            if chain.supports_cancun() {
+                assert!(chain.supports_push0(), "{chain}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_supported_verification_licenses() {
+        let licenses = Chain::Mainnet.supported_verification_licenses();
+        assert!(licenses.contains(&"MIT"));
+        assert!(licenses.contains(&"UNLICENSED"));
+        assert_eq!(licenses.iter().position(|l| *l == "BUSL-1.1"), Some(13));
+        assert!(Chain::AnvilHardhat.supported_verification_licenses().is_empty());
+
+        for chain in Chain::iter() {
+            assert_eq!(
+                chain.supported_verification_licenses().is_empty(),
+                chain.explorer_urls().is_none(),
+                "{chain}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_family() {
+        for (chain, family) in [
+            (Chain::Sepolia, ChainFamily::Ethereum),
+            (Chain::Optimism, ChainFamily::Optimism),
+            (Chain::ArbitrumNova, ChainFamily::Arbitrum),
+            (Chain::PolygonMumbai, ChainFamily::Polygon),
+            (Chain::BinanceSmartChain, ChainFamily::BinanceSmartChain),
+            (Chain::AvalancheFuji, ChainFamily::Avalanche),
+            (Chain::Fantom, ChainFamily::Fantom),
+            (Chain::Chiado, ChainFamily::Gnosis),
+            (Chain::CeloAlfajores, ChainFamily::Celo),
+            (Chain::Moonriver, ChainFamily::Moonbeam),
+            (Chain::Cronos, ChainFamily::Cronos),
+            (Chain::EvmosTestnet, ChainFamily::Evmos),
+            (Chain::Emerald, ChainFamily::Oasis),
+            (Chain::Aurora, ChainFamily::Aurora),
+            (Chain::Rsk, ChainFamily::Other),
+        ] {
+            assert_eq!(chain.family(), family, "{chain}");
+        }
+
+        // a testnet belongs to the same family as its mainnet
+        for chain in Chain::iter() {
+            if let Some(mainnet) = chain.mainnet_counterpart() {
+                assert_eq!(chain.family(), mainnet.family(), "{chain}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_mainnet() {
+        assert!(Chain::Mainnet.is_mainnet());
+        assert!(Chain::Polygon.is_mainnet());
+        assert!(Chain::ArbitrumNova.is_mainnet());
+        assert!(!Chain::Sepolia.is_mainnet());
+        assert!(!Chain::AnvilHardhat.is_mainnet());
+        assert!(Chain::Dev.is_local());
+        assert!(!Chain::Goerli.is_local());
+
+        for chain in Chain::iter() {
+            assert_eq!(chain.is_mainnet(), !chain.is_testnet() && !chain.is_local(), "{chain}");
+            // local development chains count as test networks
+            if chain.is_local() {
+                assert!(chain.is_testnet(), "{chain}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_genesis_hash() {
+        let mainnet: H256 =
+            "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3".parse().unwrap();
+        assert_eq!(Chain::Mainnet.genesis_hash(), Some(mainnet));
+        assert_eq!(Chain::from_genesis_hash(mainnet), Some(Chain::Mainnet));
+
+        let sepolia: H256 =
+            "0x25a5cc106eea7138acab33231d7160d69cb777ee0c2c553fcddf5138993e6dd9".parse().unwrap();
+        assert_eq!(Chain::from_genesis_hash(sepolia), Some(Chain::Sepolia));
+
+        assert_eq!(Chain::Optimism.genesis_hash(), None);
+        assert_eq!(Chain::from_genesis_hash(H256::zero()), None);
+
+        for chain in Chain::iter() {
+            if let Some(hash) = chain.genesis_hash() {
+                assert_eq!(Chain::from_genesis_hash(hash), Some(chain), "{chain}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_fee_decimals() {
+        assert_eq!(Chain::Mainnet.fee_decimals(), 18);
+        for chain in Chain::iter() {
+            assert_eq!(chain.fee_decimals(), 18, "{chain}");
+        }
+
+        let fee = U256::exp10(18) + U256::from(5u64);
+        assert_eq!(Chain::Polygon.format_fee(fee), "1.000000000000000005");
+
+        assert_eq!(format_fee_with_decimals(U256::from(1_500_000u64), 6), "1.500000");
+        assert_eq!(format_fee_with_decimals(U256::from(42u64), 6), "0.000042");
+    }
+
+    #[test]
+    fn test_finalized_confirmation_blocks() {
+        assert_eq!(Chain::Mainnet.finalized_confirmation_blocks(), Some(64));
+        assert_eq!(Chain::XDai.finalized_confirmation_blocks(), Some(32));
+        assert_eq!(Chain::Arbitrum.finalized_confirmation_blocks(), None);
+
+        for chain in Chain::iter().filter(Chain::has_deterministic_finality) {
+            assert_eq!(chain.finalized_confirmation_blocks(), Some(1), "{chain}");
+        }
+    }
+
+    #[test]
+    fn test_accepts_both_tx_types() {
+        assert!(Chain::Mainnet.accepts_both_tx_types());
+        assert!(Chain::Cronos.accepts_both_tx_types());
+        assert!(!Chain::Rsk.accepts_both_tx_types());
+
+        for chain in Chain::iter() {
+            if !chain.is_legacy() && chain.eip1559_unavailable_reason().is_none() {
+                assert!(chain.accepts_both_tx_types(), "{chain}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_supports_cancun() {
+        assert!(Chain::Dev.supports_cancun());
+        assert!(Chain::Dev.supports_beacon_block_root());
+        assert!(Chain::Dev.supports_blob_transactions());
+        assert!(Chain::Dev.min_blob_base_fee().is_some());
+
+        // Chains with a beacon chain expose its roots as part of Cancun, unlike L2s and
+        // sidechains which adopted Cancun's opcodes only
+        for chain in Chain::iter()
+            .filter(|chain| matches!(chain.family(), ChainFamily::Ethereum | ChainFamily::Gnosis))
+        {
+            if chain.supports_cancun() {
+                assert!(chain.supports_beacon_block_root(), "{chain}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_batching_strategy() {
+        assert_eq!(Chain::Mainnet.read_batching_strategy(), ReadBatching::JsonRpcBatch);
+        assert_eq!(Chain::AnvilHardhat.read_batching_strategy(), ReadBatching::JsonRpcBatch);
+        // Multicall3 is deployed, but JSON-RPC batching is not known to be supported
+        assert!(!Chain::Fantom.rpc_batch_supported());
+        assert_eq!(
+            Chain::Fantom.read_batching_strategy(),
+            ReadBatching::Multicall3(MULTICALL3_ADDRESS)
+        );
+        assert_eq!(Chain::Celo.read_batching_strategy(), ReadBatching::Sequential);
+
+        for chain in Chain::iter() {
+            if chain.rpc_max_batch_size().is_some() {
+                assert!(chain.rpc_batch_supported(), "{chain}");
+            }
+        }
     }
 }
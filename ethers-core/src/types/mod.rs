@@ -59,7 +59,7 @@ pub use txpool::*;
 mod trace;
 pub use trace::*;
 
-mod chain;
+pub mod chain;
 pub use chain::*;
 
 mod proof;
@@ -266,17 +266,11 @@ impl ClientBuilder {
     ///
     /// Fails if the chain is not supported by etherscan
     pub fn chain(self, chain: Chain) -> Result<Self> {
-        fn urls(
-            api: impl IntoUrl,
-            url: impl IntoUrl,
-        ) -> (reqwest::Result<Url>, reqwest::Result<Url>) {
-            (api.into_url(), url.into_url())
-        }
         let (etherscan_api_url, etherscan_url) = chain
-            .etherscan_urls()
-            .map(|(api, base)| urls(api, base))
+            .etherscan_api_url()
+            .zip(chain.etherscan_base_url())
             .ok_or_else(|| EtherscanError::ChainNotSupported(chain))?;
-        self.with_api_url(etherscan_api_url?)?.with_url(etherscan_url?)
+        self.with_api_url(etherscan_api_url)?.with_url(etherscan_url)
     }
 
     /// Configures the etherscan url